@@ -0,0 +1,4 @@
+//! The asynchronous API, available with the `async` feature.
+
+pub use crate::api::list::r#async::{list, list_lenient, list_with_meta};
+pub use crate::api::show::r#async::{show, show_with, show_with_meta, show_with_timeout};
@@ -0,0 +1,155 @@
+use crate::api::client::Ollama;
+use crate::error::OllamaError;
+use crate::structs::partialmodel::PartialModel;
+use std::sync::{Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// A client wrapper that memoizes the result of `list` for a configurable time to live.
+///
+/// Useful when the model list is read often, e.g. to render a dropdown, but rarely changes.
+/// Call [`CachingClient::invalidate_cache`] after pulling or deleting a model to force a refresh.
+/// The cache is shared by all threads using the same `CachingClient`.
+pub struct CachingClient {
+    client: Ollama,
+    ttl: Duration,
+    models: Mutex<Option<(Instant, Vec<PartialModel>)>>,
+}
+
+impl CachingClient {
+    /// Wraps `client`, keeping `list` results for `ttl`.
+    #[must_use]
+    pub const fn new(client: Ollama, ttl: Duration) -> Self {
+        Self {
+            client,
+            ttl,
+            models: Mutex::new(None),
+        }
+    }
+
+    /// Returns the wrapped client, e.g. to call endpoints that are not cached.
+    #[must_use]
+    pub const fn client(&self) -> &Ollama {
+        &self.client
+    }
+
+    /// Discards the cached model list so the next `list` call queries the server.
+    pub fn invalidate_cache(&self) {
+        *self.models.lock().unwrap_or_else(PoisonError::into_inner) = None;
+    }
+
+    /// Lists the models, blocking the current thread and querying the server only if the
+    /// cached list is missing or expired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails or if the response cannot be deserialized.
+    /// Failed requests are not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// let client = CachingClient::new(Ollama::new(), Duration::from_secs(30));
+    /// let models = client.list_blocking().unwrap();
+    /// assert_eq!(client.list_blocking().unwrap(), models);
+    /// ```
+    #[cfg(feature = "sync")]
+    pub fn list_blocking(&self) -> Result<Vec<PartialModel>, OllamaError> {
+        if let Some(models) = self.cached() {
+            return Ok(models);
+        }
+        let models = self.client.list_blocking()?;
+        self.store(&models);
+        Ok(models)
+    }
+
+    /// Lists the models, querying the server only if the cached list is missing or expired.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails or if the response cannot be deserialized.
+    /// Failed requests are not cached.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama::prelude::*;
+    /// use std::time::Duration;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let client = CachingClient::new(Ollama::new(), Duration::from_secs(30));
+    ///     let models = client.list().await.unwrap();
+    ///     assert_eq!(client.list().await.unwrap(), models);
+    /// }
+    /// ```
+    #[cfg(feature = "async")]
+    pub async fn list(&self) -> Result<Vec<PartialModel>, OllamaError> {
+        if let Some(models) = self.cached() {
+            return Ok(models);
+        }
+        let models = self.client.list().await?;
+        self.store(&models);
+        Ok(models)
+    }
+
+    /// Returns the cached model list if it has not expired yet.
+    fn cached(&self) -> Option<Vec<PartialModel>> {
+        let models = self.models.lock().unwrap_or_else(PoisonError::into_inner);
+        models
+            .as_ref()
+            .filter(|(fetched_at, _)| fetched_at.elapsed() < self.ttl)
+            .map(|(_, models)| models.clone())
+    }
+
+    /// Caches `models` as of now.
+    fn store(&self, models: &[PartialModel]) {
+        *self.models.lock().unwrap_or_else(PoisonError::into_inner) = Some((Instant::now(), models.to_vec()));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::transport::MockTransport;
+    use crate::constants::{API_TAGS_ENDPOINT, TEST_TAGS_RESPONSE};
+    use std::sync::Arc;
+
+    fn caching_client(ttl: Duration) -> (CachingClient, Arc<MockTransport>) {
+        let mock = Arc::new(MockTransport::new().with_response(API_TAGS_ENDPOINT, TEST_TAGS_RESPONSE));
+        (CachingClient::new(Ollama::new().with_transport(mock.clone()), ttl), mock)
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_list_cached_sync() {
+        let (client, mock) = caching_client(Duration::MAX);
+        let models = client.list_blocking().unwrap();
+        assert_eq!(client.list_blocking().unwrap(), models);
+        assert_eq!(mock.requests().len(), 1);
+
+        client.invalidate_cache();
+        client.list_blocking().unwrap();
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_list_cached_async() {
+        let (client, mock) = caching_client(Duration::MAX);
+        let models = client.list().await.unwrap();
+        assert_eq!(client.list().await.unwrap(), models);
+        assert_eq!(mock.requests().len(), 1);
+
+        client.invalidate_cache();
+        client.list().await.unwrap();
+        assert_eq!(mock.requests().len(), 2);
+
+        let (client, mock) = caching_client(Duration::ZERO);
+        client.list().await.unwrap();
+        client.list().await.unwrap();
+        assert_eq!(mock.requests().len(), 2);
+    }
+}
@@ -1,6 +1,27 @@
-use reqwest::Client as ReqwestClient;
-use crate::api::list::list;
+use reqwest::Url;
+use crate::api::list::{sort_models, SortBy, SortOrder};
+use crate::api::middleware::{Middleware, RequestInfo};
+use crate::api::transport::ResponseMeta;
+use crate::api::transport::{ConcurrencyLimit, HttpConfig, HttpVersion, ProxyConfig, ReqwestTransport, Transport};
+use crate::error::OllamaError;
+use crate::constants::PING_TIMEOUT;
 use crate::structs::partialmodel::PartialModel;
+use std::net::IpAddr;
+use std::sync::{Arc, OnceLock};
+use std::time::Duration;
+
+use crate::api::show::{request_body, ShowRequest};
+#[cfg(feature = "async")]
+use crate::api::show::ShowResponse;
+#[cfg(feature = "async")]
+use crate::api::transport::AsyncTransport;
+#[cfg(feature = "async")]
+use futures_util::stream::{self, StreamExt, TryStreamExt};
+#[cfg(feature = "sync")]
+use crate::api::transport::SyncTransport;
+
+#[cfg(all(unix, feature = "unix-socket"))]
+use std::path::PathBuf;
 
 #[cfg(feature = "logging")]
 use crate::logging::init_logger;
@@ -8,11 +29,27 @@ use crate::logging::init_logger;
 use log::info;
 
 /// A client for interacting with the API either synchronously or asynchronously.
+///
+/// The convenience methods are asynchronous and need the `async` feature. With the `sync`
+/// feature, each has a blocking counterpart with a `_blocking` suffix, e.g. `list_blocking`,
+/// so the default build offers both and enabling a feature never changes a method's signature.
+/// The blocking methods must not be called from within an async runtime.
+///
+/// `Ollama` is `Send + Sync` and cheap to clone, so one client can be shared across
+/// threads and tasks, e.g. in an `Arc`.
 #[derive(Clone)]
 pub struct Ollama {
     host: String,
     port: Option<u16>,
-    client: ReqwestClient,
+    normalize_names: bool,
+    loopback_proxy: bool,
+    middleware: Vec<Arc<dyn Middleware>>,
+    http: HttpConfig,
+    default_transport: Arc<ReqwestTransport>,
+    #[cfg(feature = "sync")]
+    sync_transport: Option<Arc<dyn SyncTransport>>,
+    #[cfg(feature = "async")]
+    async_transport: Option<Arc<dyn AsyncTransport>>,
 }
 
 impl Ollama {
@@ -28,14 +65,124 @@ impl Ollama {
         Self {
             host: "http://localhost".to_string(),
             port: Some(11434),
-            client: ReqwestClient::new(),
+            normalize_names: true,
+            loopback_proxy: false,
+            middleware: Vec::new(),
+            http: HttpConfig::default(),
+            default_transport: Arc::new(ReqwestTransport::default()),
+            #[cfg(feature = "sync")]
+            sync_transport: None,
+            #[cfg(feature = "async")]
+            async_transport: None,
+        }
+        .reconfigure()
+    }
+
+    /// Creates a client configured from the same environment variables as the Ollama CLI.
+    ///
+    /// - `OLLAMA_HOST` sets the host and, if given, the port (e.g. `0.0.0.0:11434` or `https://ollama.example.com`).
+    /// - `OLLAMA_API_KEY` is sent as a bearer token.
+    /// - `OLLAMA_NO_PROXY`, when set to anything but `0`/`false`, disables proxies entirely.
+    ///   Otherwise the standard `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY` variables apply.
+    ///
+    /// Unset or empty variables keep the defaults of [`Ollama::new`]. Builder methods called on the
+    /// returned client override the values taken from the environment.
+    #[must_use]
+    pub fn from_env() -> Self {
+        Self::from_vars(|name| std::env::var(name).ok())
+    }
+
+    /// Builds a client from the variables returned by `var`; see [`Ollama::from_env`].
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Self {
+        let var = |name| var(name).filter(|value| !value.trim().is_empty());
+        let mut client = Self::new();
+        if let Some(host) = var("OLLAMA_HOST") {
+            client = client.with_host(&host);
+        }
+        if let Some(token) = var("OLLAMA_API_KEY") {
+            client = client.with_bearer_token(&token);
         }
+        if var("OLLAMA_NO_PROXY").is_some_and(|value| !matches!(value.trim(), "0" | "false")) {
+            client = client.with_no_proxy();
+        }
+        client
+    }
+
+    /// Rebuilds the default transport after a change to the connection settings.
+    fn reconfigure(mut self) -> Self {
+        self.default_transport = Arc::new(ReqwestTransport::from_config(self.transport_config()));
+        self
+    }
+
+    /// Returns the settings for the default transport, bypassing the environment's proxy for a
+    /// loopback host; see [`Ollama::with_loopback_proxy`].
+    fn transport_config(&self) -> HttpConfig {
+        let mut http = self.http.clone();
+        if http.proxy == ProxyConfig::System && !self.loopback_proxy && is_loopback(&self.host) {
+            http.proxy = ProxyConfig::Disabled;
+        }
+        http
+    }
+
+    /// Returns `client`, or the [`default_client`] when `None`.
+    pub(crate) fn or_default(client: Option<&Self>) -> &Self {
+        client.unwrap_or_else(|| default_client())
     }
 
     /// Sets a custom host for the API client.
+    ///
+    /// The host is normalized: surrounding whitespace and trailing slashes are removed,
+    /// `http://` is prepended when no scheme is given, and an explicit port
+    /// (e.g. `http://host:8080`) replaces the configured port.
+    /// Use [`Ollama::try_with_host`] to reject invalid hosts up front.
     pub fn with_host(mut self, host: &str) -> Self {
-        self.host = host.to_string();
-        self
+        let proxy = self.transport_config().proxy;
+        match parse_host(host) {
+            Ok((host, port)) => {
+                self.host = host;
+                if port.is_some() {
+                    self.port = port;
+                }
+            }
+            Err(_) => self.host = with_scheme(host),
+        }
+        if self.transport_config().proxy == proxy {
+            self
+        } else {
+            self.reconfigure()
+        }
+    }
+
+    /// Sets a custom host for the API client, normalized as in [`Ollama::with_host`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OllamaError::InvalidHost`] if the host is not a valid `http` or `https` URL.
+    pub fn try_with_host(self, host: &str) -> Result<Self, OllamaError> {
+        parse_host(host)?;
+        Ok(self.with_host(host))
+    }
+
+    /// Returns a copy of this client that sends requests to `url` instead, normalized as in [`Ollama::with_host`].
+    ///
+    /// The copy shares this client's transport and connection pool, so it is cheap enough to
+    /// create per request, e.g. to route calls among several backends. Switching between a
+    /// loopback and a remote host is the exception when the proxy comes from the environment,
+    /// see [`Ollama::with_loopback_proxy`]:
+    ///
+    /// ```
+    /// use ollama::prelude::*;
+    ///
+    /// let client = Ollama::new();
+    /// let backend = client.at("http://gpu-box:11434").unwrap();
+    /// assert_eq!(backend.base_url(), "http://gpu-box:11434");
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns [`OllamaError::InvalidHost`] if `url` is not a valid `http` or `https` URL.
+    pub fn at(&self, url: &str) -> Result<Self, OllamaError> {
+        self.clone().try_with_host(url)
     }
 
     /// Sets a custom port for the API client.
@@ -44,6 +191,316 @@ impl Ollama {
         self
     }
 
+    /// Sets the maximum number of idle connections kept open per host.
+    ///
+    /// Defaults to no limit. Lower this to cap the number of sockets held open to a shared server.
+    #[must_use]
+    pub fn with_pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.http.pool_max_idle_per_host = Some(max);
+        self.reconfigure()
+    }
+
+    /// Sets how long an idle connection is kept open before it is closed.
+    ///
+    /// Defaults to 90 seconds.
+    #[must_use]
+    pub fn with_pool_idle_timeout(mut self, timeout: Duration) -> Self {
+        self.http.pool_idle_timeout = Some(timeout);
+        self.reconfigure()
+    }
+
+    /// Controls whether model names without a tag get the default `:latest` tag appended
+    /// before they are sent (`true`, the default), e.g. `llama3.1` becomes `llama3.1:latest`.
+    #[must_use]
+    pub const fn with_normalize_names(mut self, normalize: bool) -> Self {
+        self.normalize_names = normalize;
+        self
+    }
+
+    /// Returns whether model names are normalized; see [`Ollama::with_normalize_names`].
+    pub(crate) const fn normalizes_names(&self) -> bool {
+        self.normalize_names
+    }
+
+    /// Sets the total timeout of each request, from connecting until the response body has been read.
+    ///
+    /// Unlike the other connection settings, this keeps the connection pool, so a client can be
+    /// copied cheaply to give a single call its own timeout, e.g. `client.clone().with_timeout(..)`.
+    /// Without a timeout, asynchronous requests wait indefinitely and blocking requests
+    /// use `reqwest`'s default of 30 seconds. Custom transports ignore this setting.
+    ///
+    /// A total timeout bounds the whole exchange, however long the server keeps sending. That suits
+    /// the one-shot endpoints this crate wraps, such as `list` and `show`. An idle (read) timeout
+    /// instead bounds only the gap between two reads, so a long `pull` or a streamed generation
+    /// survives as long as data keeps arriving. Streaming endpoints should use an idle timeout;
+    /// a `with_read_timeout` setting is deferred until this crate wraps such an endpoint.
+    #[must_use]
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.http.timeout = Some(timeout);
+        self.default_transport = Arc::new(self.default_transport.with_config(self.transport_config()));
+        self
+    }
+
+    /// Adds `headers` to every request, replacing earlier values of the same headers.
+    ///
+    /// The headers are set per request and take precedence over client-wide ones such as the
+    /// bearer token. Like [`Ollama::with_timeout`], this keeps the connection pool, so a copy of
+    /// the client can carry headers for a single call, e.g. a trace id or an idempotency key:
+    ///
+    /// ```
+    /// use ollama::prelude::*;
+    /// use reqwest::header::{HeaderMap, HeaderValue};
+    ///
+    /// let client = Ollama::new().with_bearer_token("secret");
+    /// let mut headers = HeaderMap::new();
+    /// headers.insert("x-trace-id", HeaderValue::from_static("4bf92f3577b34da6"));
+    /// let traced = client.clone().with_headers(headers);
+    /// ```
+    ///
+    /// Custom transports ignore these headers.
+    #[must_use]
+    pub fn with_headers(mut self, headers: reqwest::header::HeaderMap) -> Self {
+        self.http.headers.extend(headers);
+        self.default_transport = Arc::new(self.default_transport.with_config(self.transport_config()));
+        self
+    }
+
+    /// Limits the number of requests this client has in flight at once to `max` (at least one).
+    ///
+    /// Further requests wait until an earlier one has completed. The limit is local to this
+    /// client and the copies made from it, e.g. with [`Ollama::at`]; it is not enforced by the
+    /// server. Blocking and asynchronous requests are limited separately. Like
+    /// [`Ollama::with_timeout`], this keeps the connection pool, and custom transports ignore it.
+    #[must_use]
+    pub fn with_max_concurrency(mut self, max: usize) -> Self {
+        self.http.concurrency_limit = Some(Arc::new(ConcurrencyLimit::new(max)));
+        self.default_transport = Arc::new(self.default_transport.with_config(self.transport_config()));
+        self
+    }
+
+    /// Controls whether error statuses fail with [`OllamaError::Api`] (`true`, the default).
+    ///
+    /// With `false`, the body of every response is returned regardless of its status, which the
+    /// `*_with_meta` functions report in [`ResponseMeta::status`](crate::api::transport::ResponseMeta::status).
+    /// Error bodies such as `{"error":"..."}` do not match the success schema, so typed calls like
+    /// `show` then fail to deserialize them; [`Ollama::get_raw`] and [`Ollama::post_raw`] return them as-is.
+    /// Like [`Ollama::with_timeout`], this keeps the connection pool, and custom transports ignore it.
+    #[must_use]
+    pub fn with_raise_on_status(mut self, raise: bool) -> Self {
+        self.http.ignore_status = !raise;
+        self.default_transport = Arc::new(self.default_transport.with_config(self.transport_config()));
+        self
+    }
+
+    /// Sends all requests through the proxy at `url`, e.g. `http://proxy.example.com:3128`.
+    ///
+    /// By default the proxy is taken from the `HTTP_PROXY`, `HTTPS_PROXY` and `NO_PROXY`
+    /// environment variables; setting a proxy here replaces them. An invalid URL is
+    /// reported by the first request.
+    #[must_use]
+    pub fn with_proxy(mut self, url: &str) -> Self {
+        self.http.proxy = ProxyConfig::All(url.to_string());
+        self.reconfigure()
+    }
+
+    /// Sends `token` in an `Authorization: Bearer` header with every request.
+    ///
+    /// A token that is not a valid header value is reported by the first request.
+    #[must_use]
+    pub fn with_bearer_token(mut self, token: &str) -> Self {
+        self.http.bearer_token = Some(token.to_string());
+        self.reconfigure()
+    }
+
+    /// Connects directly to the server, ignoring any proxy set in the environment.
+    #[must_use]
+    pub fn with_no_proxy(mut self) -> Self {
+        self.http.proxy = ProxyConfig::Disabled;
+        self.reconfigure()
+    }
+
+    /// Sends requests to a loopback host through the environment's proxy when `enabled` is `true`.
+    ///
+    /// By default a proxy taken from `HTTP_PROXY`, `HTTPS_PROXY` or `ALL_PROXY` is bypassed when the
+    /// host is `localhost`, a `*.localhost` name, or a loopback address such as `127.0.0.1` or `::1`,
+    /// as most command-line tools do; otherwise a globally configured proxy breaks access to a local
+    /// server. The host is not resolved, so other names pointing at the local machine still use the
+    /// proxy. A proxy set with [`Ollama::with_proxy`] always applies.
+    #[must_use]
+    pub fn with_loopback_proxy(mut self, enabled: bool) -> Self {
+        self.loopback_proxy = enabled;
+        self.reconfigure()
+    }
+
+    /// Speaks only HTTP/1.1 when `enabled` is `true`, e.g. for proxies that break on HTTP/2.
+    ///
+    /// By default the HTTP version is negotiated. Enabling this replaces
+    /// [`Ollama::with_http2_prior_knowledge`].
+    #[must_use]
+    pub fn with_http1_only(self, enabled: bool) -> Self {
+        self.with_http_version(HttpVersion::Http1Only, enabled)
+    }
+
+    /// Speaks HTTP/2 right away, without negotiating it first, when `enabled` is `true`.
+    ///
+    /// Only use this with servers or proxies known to accept HTTP/2 over the connection's scheme.
+    /// Enabling this replaces [`Ollama::with_http1_only`].
+    #[must_use]
+    pub fn with_http2_prior_knowledge(self, enabled: bool) -> Self {
+        self.with_http_version(HttpVersion::Http2PriorKnowledge, enabled)
+    }
+
+    /// Switches to `version` if `enabled`, or back to negotiation if `version` is disabled.
+    fn with_http_version(mut self, version: HttpVersion, enabled: bool) -> Self {
+        if enabled {
+            self.http.http_version = version;
+        } else if self.http.http_version == version {
+            self.http.http_version = HttpVersion::Negotiate;
+        }
+        self.reconfigure()
+    }
+
+    /// Disables TLS certificate verification when `accept` is `true`.
+    ///
+    /// # Warning
+    ///
+    /// This accepts any certificate, including expired ones and ones for other hosts, so
+    /// anyone on the network path can impersonate the server. Prefer
+    /// [`Ollama::with_root_certificate`] for self-signed endpoints.
+    #[cfg(any(feature = "default-tls", feature = "native-tls", feature = "rustls-tls"))]
+    #[must_use]
+    pub fn with_danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.http.accept_invalid_certs = accept;
+        self.reconfigure()
+    }
+
+    /// Trusts `certificate` in addition to the system's root certificates, e.g. for a self-signed endpoint.
+    #[cfg(any(feature = "default-tls", feature = "native-tls", feature = "rustls-tls"))]
+    #[must_use]
+    pub fn with_root_certificate(mut self, certificate: reqwest::Certificate) -> Self {
+        self.http.root_certificates.push(certificate);
+        self.reconfigure()
+    }
+
+    /// Replaces invalid UTF-8 in response bodies with `U+FFFD` instead of failing when `lossy` is `true`.
+    ///
+    /// By default a body that is not valid UTF-8, e.g. one mangled by a misbehaving proxy,
+    /// fails with [`OllamaError::InvalidUtf8`]. With the `logging` feature, each substitution logs a warning.
+    #[must_use]
+    pub fn with_lossy_decoding(mut self, lossy: bool) -> Self {
+        self.http.lossy_decoding = lossy;
+        self.reconfigure()
+    }
+
+    /// Attaches a random `X-Request-Id` header to every request when `enabled` is `true`.
+    ///
+    /// The id is included in [`OllamaError::Api`] so failures can be correlated with proxy and server logs.
+    #[cfg(feature = "uuid")]
+    #[must_use]
+    pub fn with_request_ids(mut self, enabled: bool) -> Self {
+        self.http.request_ids = enabled;
+        self.reconfigure()
+    }
+
+    /// Routes all requests through the Unix domain socket at `path` instead of TCP.
+    ///
+    /// Requests still use the configured host in their URLs (e.g. for the `Host` header),
+    /// but no DNS resolution or TCP connection takes place.
+    #[cfg(all(unix, feature = "unix-socket"))]
+    #[must_use]
+    pub fn with_unix_socket(mut self, path: impl Into<PathBuf>) -> Self {
+        self.http.unix_socket = Some(path.into());
+        self.reconfigure()
+    }
+
+    /// Sets the transport used to send requests, e.g. a [`MockTransport`](crate::api::transport::MockTransport) in tests.
+    ///
+    /// The transport is used by both the blocking and the asynchronous API, and takes
+    /// precedence over the connection settings configured on the client.
+    #[must_use]
+    // With only one of `sync` and `async` enabled, the clone is not needed.
+    #[allow(clippy::redundant_clone, clippy::needless_pass_by_value)]
+    pub fn with_transport<T: Transport + 'static>(mut self, transport: Arc<T>) -> Self {
+        #[cfg(feature = "sync")]
+        {
+            self.sync_transport = Some(transport.clone());
+        }
+        #[cfg(feature = "async")]
+        {
+            self.async_transport = Some(transport);
+        }
+        self
+    }
+
+    /// Adds `middleware` to run around every request of this client, after any added before.
+    ///
+    /// Only [`Ollama::ping`] bypasses middleware, as it is a health check rather than an API call.
+    #[must_use]
+    pub fn with_middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        self.middleware.push(middleware);
+        self
+    }
+
+    /// Returns the JSON body this client would send for `request`, without sending anything.
+    ///
+    /// Unlike [`ShowRequest::to_json_body`], this applies the client's settings, such as
+    /// [`Ollama::with_normalize_names`], so it shows exactly what the server would receive.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn dry_run(&self, request: &ShowRequest) -> serde_json::Result<String> {
+        Ok(request_body(request, self.normalizes_names())?.to_string())
+    }
+
+    /// Sends `request` through the blocking transport, running the middleware around it.
+    #[cfg(feature = "sync")]
+    pub(crate) fn execute(&self, request: RequestInfo<'_>) -> Result<(String, ResponseMeta), OllamaError> {
+        self.before(&request)?;
+        let transport = self.sync_transport();
+        let result = request.body.map_or_else(
+            || transport.get_with_meta(request.url),
+            |body| transport.post_json_with_meta(request.url, body),
+        );
+        self.after(&request, &result);
+        result
+    }
+
+    /// Sends `request` through the asynchronous transport, running the middleware around it.
+    #[cfg(feature = "async")]
+    pub(crate) async fn execute_async(&self, request: RequestInfo<'_>) -> Result<(String, ResponseMeta), OllamaError> {
+        self.before(&request)?;
+        let transport = self.async_transport();
+        let result = match request.body {
+            Some(body) => transport.post_json_with_meta(request.url, body).await,
+            None => transport.get_with_meta(request.url).await,
+        };
+        self.after(&request, &result);
+        result
+    }
+
+    /// Runs the `before` hooks of the middleware in order.
+    ///
+    /// If one fails, the `after` hooks of the middleware that already ran `before` are called with its error.
+    fn before(&self, request: &RequestInfo<'_>) -> Result<(), OllamaError> {
+        for (ran, middleware) in self.middleware.iter().enumerate() {
+            if let Err(error) = middleware.before(request) {
+                for middleware in self.middleware[..ran].iter().rev() {
+                    middleware.after(request, Err(&error));
+                }
+                return Err(error);
+            }
+        }
+        Ok(())
+    }
+
+    /// Runs the `after` hooks of the middleware in reverse order.
+    fn after(&self, request: &RequestInfo<'_>, result: &Result<(String, ResponseMeta), OllamaError>) {
+        for middleware in self.middleware.iter().rev() {
+            middleware.after(request, result.as_ref().map(|(_, meta)| meta));
+        }
+    }
+
     /// Calculates the base URL based on the host and port.
     pub fn base_url(&self) -> String {
         match self.port {
@@ -52,22 +509,468 @@ impl Ollama {
         }
     }
 
-    /// Returns a reference to the `reqwest::Client` used for making requests.
-    pub fn client(&self) -> &ReqwestClient {
-        &self.client
+    /// Returns the async `reqwest` client used by the default transport, building it on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client cannot be built from the configured settings.
+    #[cfg(feature = "async")]
+    pub fn client(&self) -> Result<&reqwest::Client, OllamaError> {
+        self.default_transport.client()
+    }
+
+    /// Returns the blocking `reqwest` client used by the default transport, building it on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client cannot be built from the configured settings.
+    #[cfg(feature = "sync")]
+    pub fn blocking_client(&self) -> Result<&reqwest::blocking::Client, OllamaError> {
+        self.default_transport.blocking_client()
+    }
+
+    /// Returns the transport used for blocking requests.
+    #[cfg(feature = "sync")]
+    #[must_use]
+    pub fn sync_transport(&self) -> &dyn SyncTransport {
+        match &self.sync_transport {
+            Some(transport) => transport.as_ref(),
+            None => self.default_transport.as_ref(),
+        }
     }
 
-    /// Lists partial models from the API using the appropriate list function.
-    #[cfg(not(feature = "async"))]
-    pub fn list(&self) -> Result<Vec<PartialModel>, Box<dyn std::error::Error>> {
-        list(Some(self))
+    /// Returns the transport used for asynchronous requests.
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub fn async_transport(&self) -> &dyn AsyncTransport {
+        match &self.async_transport {
+            Some(transport) => transport.as_ref(),
+            None => self.default_transport.as_ref(),
+        }
+    }
+
+    /// Lists partial models from the API, blocking the current thread.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails or if the response cannot be deserialized.
+    #[cfg(feature = "sync")]
+    pub fn list_blocking(&self) -> Result<Vec<PartialModel>, OllamaError> {
+        crate::api::sync::list(Some(self))
     }
 
     /// Lists partial models from the API asynchronously using the appropriate list function.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails or if the response cannot be deserialized.
+    #[cfg(feature = "async")]
+    pub async fn list(&self) -> Result<Vec<PartialModel>, OllamaError> {
+        crate::api::r#async::list(Some(self)).await
+    }
+
+    /// Lists partial models, blocking the current thread, for which `predicate` returns `true`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "sync")]
+    pub fn list_filtered_blocking<P>(&self, predicate: P) -> Result<Vec<PartialModel>, OllamaError>
+    where
+        P: FnMut(&PartialModel) -> bool,
+    {
+        Ok(self.list_blocking()?.into_iter().filter(predicate).collect())
+    }
+
+    /// Lists partial models asynchronously for which `predicate` returns `true`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "async")]
+    pub async fn list_filtered<P>(&self, predicate: P) -> Result<Vec<PartialModel>, OllamaError>
+    where
+        P: FnMut(&PartialModel) -> bool,
+    {
+        Ok(self.list().await?.into_iter().filter(predicate).collect())
+    }
+
+    /// Lists partial models, blocking the current thread, whose `name` or `model` contains `query`, ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "sync")]
+    pub fn search_blocking(&self, query: &str) -> Result<Vec<PartialModel>, OllamaError> {
+        let query = query.to_lowercase();
+        self.list_filtered_blocking(|model| matches_query(model, &query))
+    }
+
+    /// Lists partial models asynchronously whose `name` or `model` contains `query`, ignoring case.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "async")]
+    pub async fn search(&self, query: &str) -> Result<Vec<PartialModel>, OllamaError> {
+        let query = query.to_lowercase();
+        self.list_filtered(|model| matches_query(model, &query)).await
+    }
+
+    /// Lists partial models, blocking the current thread, sorted by `by` in the given `order`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "sync")]
+    pub fn list_sorted_blocking(&self, by: SortBy, order: SortOrder) -> Result<Vec<PartialModel>, OllamaError> {
+        let mut models = self.list_blocking()?;
+        sort_models(&mut models, by, order);
+        Ok(models)
+    }
+
+    /// Lists partial models asynchronously, sorted by `by` in the given `order`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "async")]
+    pub async fn list_sorted(&self, by: SortBy, order: SortOrder) -> Result<Vec<PartialModel>, OllamaError> {
+        let mut models = self.list().await?;
+        sort_models(&mut models, by, order);
+        Ok(models)
+    }
+
+    /// Checks whether the server is reachable by sending `GET /`, blocking the current thread.
+    ///
+    /// Returns `Ok(false)` if the request fails or the server responds with an error status.
+    /// With the default transport, the request times out after two seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `reqwest` client cannot be built.
+    #[cfg(feature = "sync")]
+    pub fn ping_blocking(&self) -> Result<bool, OllamaError> {
+        let url = format!("{}/", self.base_url());
+        let reachable = match &self.sync_transport {
+            Some(transport) => transport.get(&url).is_ok(),
+            None => self
+                .blocking_client()?
+                .get(&url)
+                .timeout(PING_TIMEOUT)
+                .send()
+                .is_ok_and(|response| response.status().is_success()),
+        };
+        Ok(reachable)
+    }
+
+    /// Checks asynchronously whether the server is reachable by sending `GET /`.
+    ///
+    /// Returns `Ok(false)` if the request fails or the server responds with an error status.
+    /// With the default transport, the request times out after two seconds.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `reqwest` client cannot be built.
+    #[cfg(feature = "async")]
+    pub async fn ping(&self) -> Result<bool, OllamaError> {
+        let url = format!("{}/", self.base_url());
+        let reachable = match &self.async_transport {
+            Some(transport) => transport.get(&url).await.is_ok(),
+            None => self
+                .client()?
+                .get(&url)
+                .timeout(PING_TIMEOUT)
+                .send()
+                .await
+                .is_ok_and(|response| response.status().is_success()),
+        };
+        Ok(reachable)
+    }
+
+    /// Sends `GET <base_url>/<path>`, blocking the current thread, and returns the JSON response, e.g. for an
+    /// endpoint the crate does not model yet.
+    ///
+    /// The request goes through the configured transport like typed calls, so authentication,
+    /// timeouts and error handling apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails, the server responds with an error status,
+    /// or the response is not JSON.
+    #[cfg(feature = "sync")]
+    pub fn get_raw_blocking(&self, path: &str) -> Result<serde_json::Value, OllamaError> {
+        let (raw_body, _) = self.execute(RequestInfo::get(&self.url(path)))?;
+        Ok(serde_json::from_str(&raw_body)?)
+    }
+
+    /// Sends `GET <base_url>/<path>` asynchronously and returns the JSON response, e.g. for an
+    /// endpoint the crate does not model yet.
+    ///
+    /// The request goes through the configured transport like typed calls, so authentication,
+    /// timeouts and error handling apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails, the server responds with an error status,
+    /// or the response is not JSON.
     #[cfg(feature = "async")]
-    pub async fn list(&self) -> Result<Vec<PartialModel>, Box<dyn std::error::Error>> {
-        list(Some(self)).await
+    pub async fn get_raw(&self, path: &str) -> Result<serde_json::Value, OllamaError> {
+        let (raw_body, _) = self.execute_async(RequestInfo::get(&self.url(path))).await?;
+        Ok(serde_json::from_str(&raw_body)?)
     }
+
+    /// Sends `body` to `POST <base_url>/<path>`, blocking the current thread, and returns the JSON
+    /// response, e.g. for an endpoint the crate does not model yet.
+    ///
+    /// The request goes through the configured transport like typed calls, so authentication,
+    /// timeouts and error handling apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails, the server responds with an error status,
+    /// or the response is not JSON.
+    #[cfg(feature = "sync")]
+    pub fn post_raw_blocking(&self, path: &str, body: &serde_json::Value) -> Result<serde_json::Value, OllamaError> {
+        let (raw_body, _) = self.execute(RequestInfo::post(&self.url(path), body))?;
+        Ok(serde_json::from_str(&raw_body)?)
+    }
+
+    /// Sends `body` to `POST <base_url>/<path>` asynchronously and returns the JSON response,
+    /// e.g. for an endpoint the crate does not model yet.
+    ///
+    /// The request goes through the configured transport like typed calls, so authentication,
+    /// timeouts and error handling apply.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the HTTP request fails, the server responds with an error status,
+    /// or the response is not JSON.
+    #[cfg(feature = "async")]
+    pub async fn post_raw(&self, path: &str, body: &serde_json::Value) -> Result<serde_json::Value, OllamaError> {
+        let (raw_body, _) = self.execute_async(RequestInfo::post(&self.url(path), body)).await?;
+        Ok(serde_json::from_str(&raw_body)?)
+    }
+
+    /// Returns the URL of `path` on the server, e.g. `http://localhost:11434/api/ps` for `api/ps` or `/api/ps`.
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}", self.base_url(), path.trim_start_matches('/'))
+    }
+
+    /// Returns the maximum context length of `model` in tokens, read from its verbose `show` response
+    /// while blocking the current thread.
+    ///
+    /// Returns `Ok(None)` if the server does not report a context length, e.g. when `model_info` is absent.
+    /// Use this to clamp the `num_ctx` option of a generate or chat request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `show` call fails.
+    #[cfg(feature = "sync")]
+    pub fn max_context_blocking(&self, model: &str) -> Result<Option<u64>, OllamaError> {
+        Ok(crate::api::sync::show(Some(self), model, Some(true))?.context_length())
+    }
+
+    /// Returns asynchronously the maximum context length of `model` in tokens, read from its verbose `show` response.
+    ///
+    /// Returns `Ok(None)` if the server does not report a context length, e.g. when `model_info` is absent.
+    /// Use this to clamp the `num_ctx` option of a generate or chat request.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `show` call fails.
+    #[cfg(feature = "async")]
+    pub async fn max_context(&self, model: &str) -> Result<Option<u64>, OllamaError> {
+        Ok(crate::api::r#async::show(Some(self), model, Some(true)).await?.context_length())
+    }
+
+    /// Fetches the `show` details of every model in `names`, sending at most `concurrency` requests at a time.
+    ///
+    /// The responses are returned in the same order as `names`. A `concurrency` of zero is treated as one.
+    ///
+    /// # Errors
+    ///
+    /// Returns the first error from any of the underlying `show` calls.
+    #[cfg(feature = "async")]
+    pub async fn show_all<S: AsRef<str> + Sync>(&self, names: &[S], concurrency: usize) -> Result<Vec<ShowResponse>, OllamaError> {
+        stream::iter(names)
+            .map(|name| crate::api::r#async::show(Some(self), name.as_ref(), None))
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await
+    }
+
+    /// Returns whether `model` is installed, using the `show` endpoint and blocking the current thread.
+    ///
+    /// A `404` response maps to `Ok(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any other error from the underlying `show` call, e.g. a connection failure or a `500`.
+    #[cfg(feature = "sync")]
+    pub fn exists_blocking(&self, model: &str) -> Result<bool, OllamaError> {
+        not_found_as_false(crate::api::sync::show(Some(self), model, None))
+    }
+
+    /// Returns asynchronously whether `model` is installed, using the `show` endpoint.
+    ///
+    /// A `404` response maps to `Ok(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns any other error from the underlying `show` call, e.g. a connection failure or a `500`.
+    #[cfg(feature = "async")]
+    pub async fn exists(&self, model: &str) -> Result<bool, OllamaError> {
+        not_found_as_false(crate::api::r#async::show(Some(self), model, None).await)
+    }
+
+    /// Returns whether any models are installed, blocking the current thread.
+    ///
+    /// A server with no models is not an error; this returns `Ok(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "sync")]
+    pub fn has_models_blocking(&self) -> Result<bool, OllamaError> {
+        Ok(!self.list_blocking()?.is_empty())
+    }
+
+    /// Returns asynchronously whether any models are installed.
+    ///
+    /// A server with no models is not an error; this returns `Ok(false)`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "async")]
+    pub async fn has_models(&self) -> Result<bool, OllamaError> {
+        Ok(!self.list().await?.is_empty())
+    }
+
+    /// Lists partial models, blocking the current thread, whose details report the given `family`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "sync")]
+    pub fn list_by_family_blocking(&self, family: &str) -> Result<Vec<PartialModel>, OllamaError> {
+        self.list_filtered_blocking(|model| has_family(model, family))
+    }
+
+    /// Lists partial models asynchronously whose details report the given `family`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "async")]
+    pub async fn list_by_family(&self, family: &str) -> Result<Vec<PartialModel>, OllamaError> {
+        self.list_filtered(|model| has_family(model, family)).await
+    }
+
+    /// Finds an installed model, blocking the current thread, whose `name` or `model` field equals `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "sync")]
+    pub fn find_model_blocking(&self, name: &str) -> Result<Option<PartialModel>, OllamaError> {
+        Ok(self.list_blocking()?.into_iter().find(|model| model.name == name || model.model == name))
+    }
+
+    /// Finds an installed model asynchronously whose `name` or `model` field equals `name`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the underlying `list` call fails.
+    #[cfg(feature = "async")]
+    pub async fn find_model(&self, name: &str) -> Result<Option<PartialModel>, OllamaError> {
+        Ok(self.list().await?.into_iter().find(|model| model.name == name || model.model == name))
+    }
+}
+
+static DEFAULT_CLIENT: OnceLock<Ollama> = OnceLock::new();
+
+/// Sets the client used by the free functions when they are passed `None`.
+///
+/// Call this once at startup, before any request is made without a client.
+///
+/// # Errors
+///
+/// Returns `client` back if a default client has already been set or used.
+pub fn set_default_client(client: Ollama) -> Result<(), Box<Ollama>> {
+    DEFAULT_CLIENT.set(client).map_err(Box::new)
+}
+
+/// Returns the client used by the free functions when they are passed `None`.
+///
+/// Unless [`set_default_client`] was called first, this is [`Ollama::from_env`], i.e.
+/// `http://localhost:11434` unless `OLLAMA_HOST` says otherwise.
+pub fn default_client() -> &'static Ollama {
+    DEFAULT_CLIENT.get_or_init(Ollama::from_env)
+}
+
+/// Trims whitespace and trailing slashes from `host` and prepends `http://` if it has no scheme.
+fn with_scheme(host: &str) -> String {
+    let host = host.trim().trim_end_matches('/');
+    if host.contains("://") {
+        host.to_string()
+    } else {
+        format!("http://{host}")
+    }
+}
+
+/// Returns whether the normalized `host` names the local machine without a DNS lookup.
+fn is_loopback(host: &str) -> bool {
+    let Some(name) = Url::parse(host).ok().and_then(|url| url.host_str().map(str::to_ascii_lowercase)) else {
+        return false;
+    };
+    if name == "localhost" || name.ends_with(".localhost") {
+        return true;
+    }
+    name.trim_start_matches('[').trim_end_matches(']').parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+/// Normalizes and validates `host`, splitting off any explicit port.
+fn parse_host(host: &str) -> Result<(String, Option<u16>), OllamaError> {
+    let invalid = |reason: &str| OllamaError::InvalidHost {
+        host: host.to_string(),
+        reason: reason.to_string(),
+    };
+
+    let url = Url::parse(&with_scheme(host)).map_err(|e| invalid(&e.to_string()))?;
+    if !matches!(url.scheme(), "http" | "https") {
+        return Err(invalid("scheme must be http or https"));
+    }
+    if url.query().is_some() || url.fragment().is_some() {
+        return Err(invalid("query strings and fragments are not allowed"));
+    }
+    let name = url.host_str().ok_or_else(|| invalid("missing host name"))?;
+
+    let normalized = format!("{}://{}{}", url.scheme(), name, url.path().trim_end_matches('/'));
+    Ok((normalized, url.port()))
+}
+
+/// Maps a successful response to `true` and a `404` to `false`.
+fn not_found_as_false<T>(result: Result<T, OllamaError>) -> Result<bool, OllamaError> {
+    match result {
+        Ok(_) => Ok(true),
+        Err(e) if e.is_not_found() => Ok(false),
+        Err(e) => Err(e),
+    }
+}
+
+/// Returns `true` if the `name` or `model` of `model` contains the lowercase `query`, ignoring case.
+fn matches_query(model: &PartialModel, query: &str) -> bool {
+    model.name.to_lowercase().contains(query) || model.model.to_lowercase().contains(query)
+}
+
+/// Returns `true` if `model` belongs to `family`, either as its primary family or one of its families.
+fn has_family(model: &PartialModel, family: &str) -> bool {
+    model.details.as_ref().is_some_and(|details| {
+        details.family.as_deref() == Some(family)
+            || details.families().iter().any(|f| f == family)
+    })
 }
 
 impl Default for Ollama {
@@ -79,14 +982,192 @@ impl Default for Ollama {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::constants::TEST_ENDPOINT_HOST;
-    use crate::constants::TEST_ENDPOINT_PORT;
+    use crate::api::transport::MockTransport;
+    use crate::constants::{TEST_EMPTY_TAGS_RESPONSE, TEST_SHOW_RESPONSE, TEST_TAGS_RESPONSE};
+
+    #[test]
+    fn test_with_host_normalization() {
+        let base_url = |host: &str| Ollama::new().with_host(host).base_url();
+
+        assert_eq!(base_url("0.0.0.0"), "http://0.0.0.0:11434");
+        assert_eq!(base_url("http://0.0.0.0/"), "http://0.0.0.0:11434");
+        assert_eq!(base_url("  https://example.com//  "), "https://example.com:11434");
+        assert_eq!(base_url("http://example.com:8080"), "http://example.com:8080");
+        assert_eq!(base_url("http://[::1]/"), "http://[::1]:11434");
+        assert_eq!(
+            Ollama::new().with_host("http://0.0.0.0:8080").with_port(11434).base_url(),
+            "http://0.0.0.0:11434"
+        );
+    }
+
+    #[test]
+    fn test_try_with_host_rejects_invalid_hosts() {
+        assert!(Ollama::new().try_with_host("localhost").is_ok());
+        assert!(matches!(
+            Ollama::new().try_with_host("ftp://localhost"),
+            Err(OllamaError::InvalidHost { .. })
+        ));
+        assert!(Ollama::new().try_with_host("").is_err());
+        assert!(Ollama::new().try_with_host("http://host?x=1").is_err());
+        assert!(Ollama::new().try_with_host("http://bad host").is_err());
+    }
+
+    #[test]
+    fn test_per_request_settings_keep_the_pool() {
+        let ollama = Ollama::new();
+        let timed = ollama.clone().with_timeout(Duration::from_secs(1)).with_raise_on_status(false);
+
+        #[cfg(feature = "async")]
+        assert!(std::ptr::eq(timed.client().unwrap(), ollama.client().unwrap()));
+        #[cfg(feature = "sync")]
+        assert!(std::ptr::eq(timed.blocking_client().unwrap(), ollama.blocking_client().unwrap()));
+    }
+
+    #[test]
+    fn test_at_shares_transport() {
+        let ollama = Ollama::new().with_host("http://gpu-a:11434").with_bearer_token("secret");
+        let backend = ollama.at("http://gpu-box:8080").unwrap();
+
+        assert_eq!(backend.base_url(), "http://gpu-box:8080");
+        assert_eq!(ollama.base_url(), "http://gpu-a:11434");
+        assert!(Arc::ptr_eq(&ollama.default_transport, &backend.default_transport));
+        assert!(matches!(ollama.at("ftp://gpu-box"), Err(OllamaError::InvalidHost { .. })));
+    }
+
+    #[test]
+    fn test_pool_settings_apply_to_clients() {
+        let ollama = Ollama::new()
+            .with_pool_max_idle_per_host(4)
+            .with_pool_idle_timeout(Duration::from_secs(30));
+
+        assert_eq!(ollama.http.pool_max_idle_per_host, Some(4));
+        assert_eq!(ollama.http.pool_idle_timeout, Some(Duration::from_secs(30)));
+        #[cfg(feature = "async")]
+        assert!(ollama.client().is_ok());
+        #[cfg(feature = "sync")]
+        assert!(std::thread::spawn(move || ollama.blocking_client().is_ok()).join().unwrap());
+    }
+
+    #[test]
+    fn test_proxy_settings() {
+        assert_eq!(Ollama::new().http.proxy, ProxyConfig::System);
+        assert_eq!(Ollama::new().with_no_proxy().http.proxy, ProxyConfig::Disabled);
+
+        let ollama = Ollama::new().with_no_proxy().with_proxy("http://proxy.example.com:3128");
+        assert_eq!(ollama.http.proxy, ProxyConfig::All("http://proxy.example.com:3128".to_string()));
+        #[cfg(feature = "async")]
+        assert!(ollama.client().is_ok());
+        #[cfg(feature = "async")]
+        assert!(Ollama::new().with_proxy("not a url").client().is_err());
+    }
+
+    #[test]
+    fn test_http_version_settings() {
+        assert_eq!(Ollama::new().http.http_version, HttpVersion::Negotiate);
+
+        let ollama = Ollama::new().with_http2_prior_knowledge(true).with_http1_only(true);
+        assert_eq!(ollama.http.http_version, HttpVersion::Http1Only);
+        assert_eq!(ollama.clone().with_http2_prior_knowledge(false).http.http_version, HttpVersion::Http1Only);
+        assert_eq!(ollama.with_http1_only(false).http.http_version, HttpVersion::Negotiate);
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_http_version_is_applied() {
+        use crate::api::transport::serve_once;
+
+        let (url, server) = serve_once("200 OK", "{}");
+        let ollama = Ollama::new().with_host(&url).with_http1_only(true);
+        assert_eq!(ollama.blocking_client().unwrap().get(&url).send().unwrap().version(), reqwest::Version::HTTP_11);
+        server.join().unwrap();
+
+        // An HTTP/1.1 server does not understand the HTTP/2 connection preface.
+        let (url, server) = serve_once("200 OK", "{}");
+        let ollama = Ollama::new().with_host(&url).with_http2_prior_knowledge(true);
+        assert!(ollama.blocking_client().unwrap().get(&url).send().is_err());
+        server.join().unwrap();
+    }
 
-    #[cfg(not(feature = "async"))]
+    #[cfg(any(feature = "default-tls", feature = "native-tls", feature = "rustls-tls"))]
+    #[test]
+    fn test_tls_settings() {
+        const CERTIFICATE: &[u8] = include_bytes!("../../tests/fixtures/self_signed.pem");
+
+        let ollama = Ollama::new()
+            .with_danger_accept_invalid_certs(true)
+            .with_root_certificate(reqwest::Certificate::from_pem(CERTIFICATE).unwrap());
+
+        assert!(ollama.http.accept_invalid_certs);
+        assert_eq!(ollama.http.root_certificates.len(), 1);
+        #[cfg(feature = "async")]
+        assert!(ollama.client().is_ok());
+    }
+
+    #[test]
+    fn test_loopback_bypasses_system_proxy() {
+        for host in ["localhost", "http://127.0.0.1:8080", "127.1.2.3", "[::1]:11434", "https://api.LOCALHOST"] {
+            assert!(is_loopback(&Ollama::new().with_host(host).host), "{host}");
+        }
+        for host in ["example.com", "localhost.example.com", "10.0.0.1", "[::2]"] {
+            assert!(!is_loopback(&Ollama::new().with_host(host).host), "{host}");
+        }
+
+        let local = Ollama::new();
+        assert_eq!(local.http.proxy, ProxyConfig::System);
+        assert_eq!(local.transport_config().proxy, ProxyConfig::Disabled);
+        assert_eq!(local.clone().with_loopback_proxy(true).transport_config().proxy, ProxyConfig::System);
+
+        let proxied = local.clone().with_proxy("http://proxy.example.com:3128");
+        assert!(matches!(proxied.transport_config().proxy, ProxyConfig::All(_)));
+
+        let remote = local.at("http://gpu-box:11434").unwrap();
+        assert_eq!(remote.transport_config().proxy, ProxyConfig::System);
+        assert!(!Arc::ptr_eq(&local.default_transport, &remote.default_transport));
+        let other = local.at("http://127.0.0.1:11434").unwrap();
+        assert!(Arc::ptr_eq(&local.default_transport, &other.default_transport));
+    }
+
+    #[test]
+    fn test_from_vars() {
+        let vars = |pairs: &'static [(&'static str, &'static str)]| {
+            move |name: &str| pairs.iter().find(|(key, _)| *key == name).map(|(_, value)| (*value).to_string())
+        };
+
+        let ollama = Ollama::from_vars(vars(&[]));
+        assert_eq!(ollama.base_url(), "http://localhost:11434");
+        assert_eq!(ollama.http.proxy, ProxyConfig::System);
+        assert_eq!(ollama.http.bearer_token, None);
+
+        let ollama = Ollama::from_vars(vars(&[
+            ("OLLAMA_HOST", "0.0.0.0:8080"),
+            ("OLLAMA_API_KEY", "secret"),
+            ("OLLAMA_NO_PROXY", "1"),
+        ]));
+        assert_eq!(ollama.base_url(), "http://0.0.0.0:8080");
+        assert_eq!(ollama.http.bearer_token.as_deref(), Some("secret"));
+        assert_eq!(ollama.http.proxy, ProxyConfig::Disabled);
+
+        let ollama = Ollama::from_vars(vars(&[("OLLAMA_HOST", " "), ("OLLAMA_NO_PROXY", "false")])).with_port(1234);
+        assert_eq!(ollama.base_url(), "http://localhost:1234");
+        assert_eq!(ollama.http.proxy, ProxyConfig::System);
+    }
+
+    #[cfg(feature = "async")]
+    #[test]
+    fn test_invalid_bearer_token() {
+        assert!(Ollama::new().with_bearer_token("secret").client().is_ok());
+        assert!(matches!(
+            Ollama::new().with_bearer_token("bad\ntoken").client(),
+            Err(OllamaError::InvalidHeader(_))
+        ));
+    }
+
+    #[cfg(feature = "sync")]
     #[test]
     fn test_ollama_sync_list() {
-        let ollama = Ollama::new().with_host(TEST_ENDPOINT_HOST).with_port(TEST_ENDPOINT_PORT);
-        let result = ollama.list();
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        let ollama = Ollama::new().with_transport(mock);
+        let result = ollama.list_blocking();
 
         match result {
             Ok(models) => assert!(!models.is_empty(), "Model list should not be empty"),
@@ -97,7 +1178,8 @@ mod tests {
     #[cfg(feature = "async")]
     #[tokio::test]
     async fn test_ollama_async_list() {
-        let ollama = Ollama::new().with_host(TEST_ENDPOINT_HOST).with_port(TEST_ENDPOINT_PORT);
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        let ollama = Ollama::new().with_transport(mock);
         let result = ollama.list().await;
 
         match result {
@@ -105,4 +1187,377 @@ mod tests {
             Err(e) => panic!("Failed to fetch models: {}", e),
         }
     }
+
+    #[cfg(all(feature = "sync", feature = "async"))]
+    #[tokio::test]
+    async fn test_ollama_sync_and_async_share_transport() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        let ollama = Ollama::new().with_transport(mock.clone());
+
+        let blocking = crate::api::sync::list(Some(&ollama)).unwrap();
+        let asynchronous = crate::api::r#async::list(Some(&ollama)).await.unwrap();
+
+        assert_eq!(blocking, asynchronous);
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_ollama_sync_ping() {
+        let mock = Arc::new(MockTransport::new().with_response("/", "Ollama is running"));
+        assert!(Ollama::new().with_transport(mock).ping_blocking().unwrap());
+        assert!(!Ollama::new().with_transport(Arc::new(MockTransport::new())).ping_blocking().unwrap());
+        assert!(!Ollama::new().with_host("http://127.0.0.1").with_port(9).ping_blocking().unwrap());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_ollama_async_ping() {
+        let mock = Arc::new(MockTransport::new().with_response("/", "Ollama is running"));
+        assert!(Ollama::new().with_transport(mock).ping().await.unwrap());
+        assert!(!Ollama::new().with_transport(Arc::new(MockTransport::new())).ping().await.unwrap());
+        assert!(!Ollama::new().with_host("http://127.0.0.1").with_port(9).ping().await.unwrap());
+    }
+
+    /// Records the hooks it sees and rejects requests to `/api/blocked`.
+    #[derive(Default)]
+    struct Recorder(std::sync::Mutex<Vec<String>>);
+
+    impl Middleware for Recorder {
+        fn before(&self, request: &RequestInfo<'_>) -> Result<(), OllamaError> {
+            self.0.lock().unwrap().push(format!("before {} {}", request.method, request.url));
+            if request.url.ends_with("/api/blocked") {
+                return Err(OllamaError::Transport("circuit open".to_string()));
+            }
+            Ok(())
+        }
+
+        fn after(&self, request: &RequestInfo<'_>, outcome: Result<&ResponseMeta, &OllamaError>) {
+            self.0.lock().unwrap().push(format!("after {} {}", request.method, outcome.is_ok()));
+        }
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_ollama_sync_middleware() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        let recorder = Arc::new(Recorder::default());
+        let ollama = Ollama::new().with_transport(mock.clone()).with_middleware(recorder.clone());
+
+        crate::api::sync::list(Some(&ollama)).unwrap();
+        crate::api::sync::show(Some(&ollama), "llama3.1", None).unwrap_err();
+        let base_url = ollama.base_url();
+
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [
+                format!("before GET {base_url}/api/tags"),
+                "after GET true".to_string(),
+                format!("before POST {base_url}/api/show"),
+                "after POST false".to_string(),
+            ]
+        );
+        assert_eq!(mock.requests().len(), 2);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_ollama_async_middleware() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/blocked", "{}"));
+        let recorder = Arc::new(Recorder::default());
+        let ollama = Ollama::new().with_transport(mock.clone()).with_middleware(recorder.clone());
+
+        let error = ollama.get_raw("/api/blocked").await.unwrap_err();
+        assert!(matches!(error, OllamaError::Transport(message) if message == "circuit open"));
+        assert_eq!(recorder.0.lock().unwrap().len(), 1);
+        assert!(mock.requests().is_empty());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_ollama_sync_middleware_abort_runs_earlier_after_hooks() {
+        /// Rejects every request, like an open circuit breaker.
+        struct OpenCircuit;
+
+        impl Middleware for OpenCircuit {
+            fn before(&self, _request: &RequestInfo<'_>) -> Result<(), OllamaError> {
+                Err(OllamaError::Transport("circuit open".to_string()))
+            }
+
+            fn after(&self, _request: &RequestInfo<'_>, _outcome: Result<&ResponseMeta, &OllamaError>) {
+                panic!("after called although before failed");
+            }
+        }
+
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        let recorder = Arc::new(Recorder::default());
+        let open: Arc<dyn Middleware> = Arc::new(OpenCircuit);
+        let ollama = Ollama::new().with_transport(mock.clone()).with_middleware(recorder.clone()).with_middleware(open);
+
+        let error = crate::api::sync::list(Some(&ollama)).unwrap_err();
+        assert!(matches!(error, OllamaError::Transport(message) if message == "circuit open"));
+        assert_eq!(
+            *recorder.0.lock().unwrap(),
+            [format!("before GET {}/api/tags", ollama.base_url()), "after GET false".to_string()]
+        );
+        assert!(mock.requests().is_empty());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_ollama_sync_raw() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/ps", r#"{"models":[]}"#));
+        let ollama = Ollama::new().with_transport(mock.clone());
+
+        assert_eq!(ollama.get_raw_blocking("api/ps").unwrap(), serde_json::json!({ "models": [] }));
+        let body = serde_json::json!({ "model": "llama3.1" });
+        assert_eq!(ollama.post_raw_blocking("/api/ps", &body).unwrap()["models"], serde_json::json!([]));
+        assert_eq!(mock.requests()[1].url, format!("{}/api/ps", ollama.base_url()));
+        assert_eq!(mock.requests()[1].body, Some(body));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_ollama_async_raw() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/ps", r#"{"models":[]}"#));
+        let ollama = Ollama::new().with_transport(mock.clone());
+
+        assert_eq!(ollama.get_raw("api/ps").await.unwrap(), serde_json::json!({ "models": [] }));
+        let body = serde_json::json!({ "model": "llama3.1" });
+        assert_eq!(ollama.post_raw("/api/ps", &body).await.unwrap()["models"], serde_json::json!([]));
+        assert_eq!(mock.requests()[1].url, format!("{}/api/ps", ollama.base_url()));
+        assert_eq!(mock.requests()[1].body, Some(body));
+        assert!(ollama.get_raw("/api/unknown").await.is_err());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_ollama_sync_max_context() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/show", TEST_SHOW_RESPONSE));
+        assert_eq!(Ollama::new().with_transport(mock).max_context_blocking("llama3.1").unwrap(), Some(131_072));
+
+        let mock = Arc::new(MockTransport::new().with_response("/api/show", r#"{"details":{}}"#));
+        assert_eq!(Ollama::new().with_transport(mock).max_context_blocking("llama3.1").unwrap(), None);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_ollama_async_max_context() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/show", TEST_SHOW_RESPONSE));
+        assert_eq!(Ollama::new().with_transport(mock).max_context("llama3.1").await.unwrap(), Some(131_072));
+
+        let mock = Arc::new(MockTransport::new().with_response("/api/show", r#"{"details":{}}"#));
+        assert_eq!(Ollama::new().with_transport(mock).max_context("llama3.1").await.unwrap(), None);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_ollama_show_all() {
+        use crate::api::transport::{AsyncTransport, TransportFuture};
+        use std::time::Duration;
+
+        /// Answers `show` with the requested name as the modelfile, replying sooner to later names.
+        struct ReverseDelays(Vec<&'static str>);
+
+        impl AsyncTransport for ReverseDelays {
+            fn get<'a>(&'a self, _url: &'a str) -> TransportFuture<'a> {
+                unreachable!("show_all only posts")
+            }
+
+            fn post_json<'a>(&'a self, _url: &'a str, body: &'a serde_json::Value) -> TransportFuture<'a> {
+                Box::pin(async move {
+                    let name = body["name"].as_str().unwrap();
+                    let position = self.0.iter().position(|candidate| *candidate == name).unwrap();
+                    let delay = 20 * (self.0.len() - position);
+                    tokio::time::sleep(Duration::from_millis(delay as u64)).await;
+                    let mut response: serde_json::Value = serde_json::from_str(TEST_SHOW_RESPONSE).unwrap();
+                    response["modelfile"] = name.into();
+                    Ok(response.to_string())
+                })
+            }
+        }
+
+        #[cfg(feature = "sync")]
+        impl crate::api::transport::SyncTransport for ReverseDelays {
+            fn get(&self, _url: &str) -> Result<String, OllamaError> {
+                unreachable!("show_all is async")
+            }
+
+            fn post_json(&self, _url: &str, _body: &serde_json::Value) -> Result<String, OllamaError> {
+                unreachable!("show_all is async")
+            }
+        }
+
+        let names = ["llama3.1:8b", "llama3.1:70b", "llama3.1:405b"];
+        let ollama = Ollama::new().with_transport(Arc::new(ReverseDelays(names.to_vec())));
+
+        let responses = ollama.show_all(&names, names.len()).await.unwrap();
+        let modelfiles: Vec<_> = responses.iter().map(|response| response.modelfile.as_str()).collect();
+        assert_eq!(modelfiles, names);
+
+        assert!(ollama.show_all::<&str>(&[], 0).await.unwrap().is_empty());
+        let failing = Ollama::new().with_transport(Arc::new(MockTransport::new()));
+        assert!(failing.show_all(&names, 2).await.is_err());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_not_found_as_false() {
+        use crate::api::transport::serve_once;
+
+        let (url, server) = serve_once("404 Not Found", r#"{"error":"model 'missing' not found"}"#);
+        let result = crate::api::sync::show(Some(&Ollama::new().with_host(&url)), "missing", None);
+        server.join().unwrap();
+        assert!(!not_found_as_false(result).unwrap());
+
+        let (url, server) = serve_once("500 Internal Server Error", r#"{"error":"boom"}"#);
+        let result = crate::api::sync::show(Some(&Ollama::new().with_host(&url)), "llama3.1", None);
+        server.join().unwrap();
+        assert!(matches!(not_found_as_false(result), Err(OllamaError::Api { status: 500, .. })));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_ollama_async_exists() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/show", TEST_SHOW_RESPONSE));
+        assert!(Ollama::new().with_transport(mock).exists("llama3.1").await.unwrap());
+        assert!(Ollama::new().with_transport(Arc::new(MockTransport::new())).exists("llama3.1").await.is_err());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_ollama_sync_exists() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/show", TEST_SHOW_RESPONSE));
+        assert!(Ollama::new().with_transport(mock).exists_blocking("llama3.1").unwrap());
+        assert!(Ollama::new().with_transport(Arc::new(MockTransport::new())).exists_blocking("llama3.1").is_err());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_ollama_sync_has_models() {
+        let empty = Arc::new(MockTransport::new().with_response("/api/tags", TEST_EMPTY_TAGS_RESPONSE));
+        let ollama = Ollama::new().with_transport(empty);
+        assert!(ollama.list_blocking().unwrap().is_empty());
+        assert!(!ollama.has_models_blocking().unwrap());
+
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        assert!(Ollama::new().with_transport(mock).has_models_blocking().unwrap());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_ollama_async_has_models() {
+        let empty = Arc::new(MockTransport::new().with_response("/api/tags", TEST_EMPTY_TAGS_RESPONSE));
+        let ollama = Ollama::new().with_transport(empty);
+        assert!(ollama.list().await.unwrap().is_empty());
+        assert!(!ollama.has_models().await.unwrap());
+
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        assert!(Ollama::new().with_transport(mock).has_models().await.unwrap());
+    }
+
+    #[test]
+    fn test_matches_query() {
+        let mut model = PartialModel::new("Llama3.1:latest");
+        model.model = "llama3.1:8b-Instruct-Q6_K".to_string();
+
+        for query in ["llama", "LLAMA3.1:LATEST", "Instruct-q6"] {
+            assert!(matches_query(&model, &query.to_lowercase()), "{query}");
+        }
+        assert!(!matches_query(&model, "gemma"));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_ollama_async_search() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        let ollama = Ollama::new().with_transport(mock);
+
+        assert_eq!(ollama.search("LLaMA3.1").await.unwrap().len(), 1);
+        assert_eq!(ollama.search("Q6_k").await.unwrap().len(), 1);
+        assert!(ollama.search("gemma").await.unwrap().is_empty());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_ollama_sync_search() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        let ollama = Ollama::new().with_transport(mock);
+
+        assert_eq!(ollama.search_blocking("LLaMA3.1").unwrap().len(), 1);
+        assert!(ollama.search_blocking("gemma").unwrap().is_empty());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_ollama_sync_find_model() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        let ollama = Ollama::new().with_transport(mock);
+
+        assert!(ollama.find_model_blocking("llama3.1:8b-instruct-q6_K").unwrap().is_some());
+        assert!(ollama.find_model_blocking("llama3.1").unwrap().is_none());
+        assert_eq!(ollama.list_by_family_blocking("llama").unwrap().len(), 1);
+        assert!(ollama.list_by_family_blocking("gemma").unwrap().is_empty());
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_ollama_async_find_model() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/tags", TEST_TAGS_RESPONSE));
+        let ollama = Ollama::new().with_transport(mock);
+
+        assert!(ollama.find_model("llama3.1:8b-instruct-q6_K").await.unwrap().is_some());
+        assert!(ollama.find_model("llama3.1").await.unwrap().is_none());
+        assert_eq!(ollama.list_by_family("llama").await.unwrap().len(), 1);
+        assert!(ollama.list_by_family("gemma").await.unwrap().is_empty());
+    }
+
+    #[cfg(all(unix, feature = "unix-socket", feature = "sync"))]
+    #[test]
+    fn test_ollama_sync_unix_socket() {
+        use std::io::{Read, Write};
+        use std::os::unix::net::UnixListener;
+
+        let path = std::env::temp_dir().join(format!("ollama-sync-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut buffer = [0; 1024];
+            let _ = stream.read(&mut buffer).unwrap();
+            let body = r#"{"models":[]}"#;
+            write!(stream, "HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n{body}", body.len()).unwrap();
+        });
+
+        let ollama = Ollama::new().with_unix_socket(&path);
+        assert!(crate::api::sync::list(Some(&ollama)).unwrap().is_empty());
+
+        server.join().unwrap();
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[cfg(all(unix, feature = "unix-socket", feature = "async"))]
+    #[tokio::test]
+    async fn test_ollama_async_unix_socket() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+        use tokio::net::UnixListener;
+
+        let path = std::env::temp_dir().join(format!("ollama-async-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixListener::bind(&path).unwrap();
+        let server = tokio::spawn(async move {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = [0; 1024];
+            let _ = stream.read(&mut buffer).await.unwrap();
+            let body = r#"{"models":[]}"#;
+            let response = format!("HTTP/1.1 200 OK\r\ncontent-length: {}\r\n\r\n{body}", body.len());
+            stream.write_all(response.as_bytes()).await.unwrap();
+        });
+
+        let ollama = Ollama::new().with_unix_socket(&path);
+        assert!(ollama.list().await.unwrap().is_empty());
+
+        server.await.unwrap();
+        std::fs::remove_file(&path).unwrap();
+    }
 }
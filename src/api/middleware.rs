@@ -0,0 +1,81 @@
+use crate::api::transport::ResponseMeta;
+use crate::error::OllamaError;
+
+/// A request about to be sent, as seen by a [`Middleware`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[non_exhaustive]
+pub struct RequestInfo<'a> {
+    /// `GET` or `POST`.
+    pub method: &'static str,
+    pub url: &'a str,
+    /// The JSON body of a `POST` request.
+    pub body: Option<&'a serde_json::Value>,
+}
+
+impl<'a> RequestInfo<'a> {
+    /// Describes a `GET` request to `url`.
+    pub(crate) const fn get(url: &'a str) -> Self {
+        Self {
+            method: "GET",
+            url,
+            body: None,
+        }
+    }
+
+    /// Describes a `POST` request to `url` with `body` as JSON.
+    pub(crate) const fn post(url: &'a str, body: &'a serde_json::Value) -> Self {
+        Self {
+            method: "POST",
+            url,
+            body: Some(body),
+        }
+    }
+}
+
+/// Hooks run around every API request of a client, e.g. for metrics or circuit breaking.
+///
+/// Middleware is added with [`Ollama::with_middleware`](crate::api::client::Ollama::with_middleware)
+/// and runs for both the blocking and the asynchronous API, regardless of the transport.
+/// `before` hooks run in the order the middleware was added, `after` hooks in reverse order.
+///
+/// # Examples
+///
+/// ```
+/// use ollama::prelude::*;
+/// use std::sync::atomic::{AtomicUsize, Ordering};
+/// use std::sync::Arc;
+///
+/// #[derive(Default)]
+/// struct CountFailures(AtomicUsize);
+///
+/// impl Middleware for CountFailures {
+///     fn after(&self, _request: &RequestInfo<'_>, outcome: Result<&ResponseMeta, &OllamaError>) {
+///         if outcome.is_err() {
+///             self.0.fetch_add(1, Ordering::Relaxed);
+///         }
+///     }
+/// }
+///
+/// let client = Ollama::new().with_middleware(Arc::new(CountFailures::default()));
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called before `request` is sent.
+    ///
+    /// Every middleware whose `before` returns `Ok` gets exactly one `after` call for the request,
+    /// even if a later middleware aborts it, so counters of requests in flight stay balanced.
+    ///
+    /// # Errors
+    ///
+    /// Returning an error aborts the request with that error, e.g. while a circuit breaker is open.
+    /// The remaining `before` hooks and the request are skipped, and the middleware that already
+    /// ran `before` get `after` with this error. The failing middleware itself gets no `after` call.
+    fn before(&self, request: &RequestInfo<'_>) -> Result<(), OllamaError> {
+        let _ = request;
+        Ok(())
+    }
+
+    /// Called once `request` has completed, with its response metadata or error.
+    fn after(&self, request: &RequestInfo<'_>, outcome: Result<&ResponseMeta, &OllamaError>) {
+        let _ = (request, outcome);
+    }
+}
@@ -1,3 +1,12 @@
+pub(crate) mod cache;
 pub(crate) mod client;
 pub(crate) mod list;
-pub(crate) mod show;
\ No newline at end of file
+pub(crate) mod middleware;
+pub(crate) mod show;
+pub(crate) mod transport;
+
+#[cfg(feature = "sync")]
+pub mod sync;
+
+#[cfg(feature = "async")]
+pub mod r#async;
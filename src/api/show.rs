@@ -1,166 +1,427 @@
 use crate::constants::SHOW_ENDPOINT;
-use crate::structs::model::ModelDetails;
-use crate::api::client::Ollama;
+use crate::structs::model::{DetailsSummary, ModelDetails};
+use crate::structs::modelinfo::ModelInfo;
+use crate::structs::partialmodel::PartialModel;
 use serde::{Deserialize, Serialize};
-use std::error::Error;
+use std::collections::HashMap;
 
 /// Struct representing the request body for the `show` API call.
 ///
 /// The `name` field is mandatory, and `verbose` is optional.
 /// If `verbose` is set to `Some(true)`, the API will return a more detailed response.
 ///
-#[derive(Serialize, Deserialize, Debug)]
+/// # Examples
+///
+/// ```
+/// use ollama::prelude::ShowRequest;
+///
+/// let request = ShowRequest::builder().name("llama3.1").verbose(true);
+/// assert_eq!(request.verbose, Some(true));
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
 pub struct ShowRequest {
     pub name: String,
     pub verbose: Option<bool>,
 }
 
+impl ShowRequest {
+    /// Creates a request for the model `name`.
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            verbose: None,
+        }
+    }
+
+    /// Creates an empty request to be filled in with the builder methods.
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Sets the model to show.
+    #[must_use]
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = name.into();
+        self
+    }
+
+    /// Requests the full `model_info` and tokenizer metadata.
+    #[must_use]
+    pub const fn verbose(mut self, verbose: bool) -> Self {
+        self.verbose = Some(verbose);
+        self
+    }
+}
+
+impl From<&PartialModel> for ShowRequest {
+    /// Creates a verbose request for the model of a `list` entry, as used by [`PartialModel::show`].
+    fn from(model: &PartialModel) -> Self {
+        Self::new(&model.model).verbose(true)
+    }
+}
+
 /// Struct representing the response from the `show` API call.
 ///
 /// The response includes details like the modelfile, parameters, template, and model details.
 /// The `model_info` field may contain additional model-specific metadata if requested with `verbose: true`.
+/// Some server versions omit `modelfile`, `parameters` or `template`; these default to an empty string.
+/// New fields may be added as the API evolves, so the struct cannot be built outside this crate.
 ///
 #[derive(Serialize, Deserialize, Debug)]
+#[non_exhaustive]
 pub struct ShowResponse {
+    #[serde(default)]
     pub modelfile: String,
+    #[serde(default)]
     pub parameters: String,
+    #[serde(default)]
     pub template: String,
     pub details: ModelDetails,
     pub model_info: Option<serde_json::Value>,
 }
 
-/// Synchronously sends a request to the `show` endpoint to retrieve detailed information about a model.
-///
-/// # Arguments
-///
-/// * `client` - An optional reference to a `Ollama` instance.
-/// * `name` - The name of the model to retrieve information about.
-/// * `verbose` - An optional boolean flag to request more detailed information.
-///
-/// # Returns
-///
-/// A `Result` containing a `ShowResponse` with the model details, or an error if the request fails.
-///
-/// # Examples
-///
-/// ```
-/// use ollama::prelude::*;
-///
-/// let ollama = Ollama::new().with_host("http://0.0.0.0").with_port(11434);
-/// let response = show(Some(&ollama), "llama3.1:8b-instruct-q6_K", Some(true)).unwrap();
-/// assert!(response.modelfile.contains("llama3.1"));
-/// ```
+impl ShowResponse {
+    /// Returns a typed view over `model_info`, if the server returned it.
+    #[must_use]
+    pub fn info(&self) -> Option<ModelInfo<'_>> {
+        self.model_info.as_ref().and_then(ModelInfo::new)
+    }
+
+    /// Returns the model architecture (`general.architecture`), e.g. `llama`.
+    #[must_use]
+    pub fn architecture(&self) -> Option<&str> {
+        self.info()?.architecture()
+    }
+
+    /// Returns a compact summary of the model's details and capabilities; see [`DetailsSummary`].
+    #[must_use]
+    pub fn details_summary(&self) -> DetailsSummary {
+        DetailsSummary::new(&self.details, self.model_info.as_ref(), &self.template)
+    }
+
+    /// Parses the `parameters` text into a map from parameter name to its values.
+    ///
+    /// Each line holds a name followed by a value, e.g. `temperature 0.7` or `stop "<|eot_id|>"`.
+    /// Repeated names such as `stop` collect all their values in order, and quoted values are unquoted.
+    #[must_use]
+    pub fn parameters_map(&self) -> HashMap<String, Vec<String>> {
+        let mut parameters = HashMap::<String, Vec<String>>::new();
+        for line in self.parameters.lines() {
+            let Some((key, value)) = line.trim().split_once(char::is_whitespace) else {
+                continue;
+            };
+            parameters.entry(key.to_string()).or_default().push(unquote(value.trim()));
+        }
+        parameters
+    }
+
+    /// Returns the maximum context length (`<architecture>.context_length`).
+    #[must_use]
+    pub fn context_length(&self) -> Option<u64> {
+        self.info()?.context_length()
+    }
+}
+
+impl ShowRequest {
+    /// Returns the JSON body this request is sent as, e.g. to assert on it in tests.
+    ///
+    /// See [`Ollama::dry_run`](crate::api::client::Ollama::dry_run) for the body a particular client would send.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails.
+    pub fn to_json_body(&self) -> serde_json::Result<String> {
+        Ok(request_body(self, false)?.to_string())
+    }
+}
+
+/// Returns the JSON body for `request`, with the model name normalized if `normalize` is set,
+/// as it is for clients that [normalize names](crate::api::client::Ollama::with_normalize_names).
+pub fn request_body(request: &ShowRequest, normalize: bool) -> serde_json::Result<serde_json::Value> {
+    if normalize {
+        serde_json::to_value(ShowRequest {
+            name: normalize_name(&request.name),
+            ..request.clone()
+        })
+    } else {
+        serde_json::to_value(request)
+    }
+}
+
+/// Appends the default `:latest` tag to `name` if it has none, e.g. `llama3.1` becomes `llama3.1:latest`.
 ///
-/// # Errors
+/// A colon before the last `/` is a registry port rather than a tag, and names pinned
+/// to a digest (`name@sha256:...`) are left unchanged.
+fn normalize_name(name: &str) -> String {
+    let last_segment = name.rsplit('/').next().unwrap_or(name);
+    if name.is_empty() || name.contains('@') || last_segment.contains(':') {
+        name.to_string()
+    } else {
+        format!("{name}:latest")
+    }
+}
+
+/// Removes the quotes and escapes from a value written as a quoted string, e.g. `"say \"hi\""`.
 ///
-/// This function returns an error if the HTTP request fails or if the response cannot be deserialized.
-#[cfg(not(feature = "async"))]
-pub fn show(client: Option<&Ollama>, name: &str, verbose: Option<bool>) -> Result<ShowResponse, Box<dyn Error>> {
-    use reqwest::blocking::Client as BlockingClient;
-
-    let url = match client {
-        Some(client) => format!("{}{}", client.base_url(), SHOW_ENDPOINT),
-        None => format!("{}{}", crate::constants::TEST_ENDPOINT_HOST, SHOW_ENDPOINT),
+/// Values that are not quoted are returned unchanged.
+fn unquote(value: &str) -> String {
+    let Some(inner) = value.strip_prefix('"').and_then(|value| value.strip_suffix('"')) else {
+        return value.to_string();
     };
+    let mut unquoted = String::with_capacity(inner.len());
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unquoted.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('n') => unquoted.push('\n'),
+            Some('t') => unquoted.push('\t'),
+            Some('r') => unquoted.push('\r'),
+            Some(escaped) => unquoted.push(escaped),
+            None => unquoted.push('\\'),
+        }
+    }
+    unquoted
+}
 
-    #[cfg(feature = "logging")]
-    log::info!("Sending synchronous request to URL: {}", url);
+#[cfg(feature = "sync")]
+pub mod sync {
+    use super::{request_body, ShowRequest, ShowResponse, SHOW_ENDPOINT};
+    use crate::api::client::Ollama;
+    use crate::api::middleware::RequestInfo;
+    use crate::api::transport::ResponseMeta;
+    use crate::error::OllamaError;
+    use crate::trace::traced;
+    use std::time::Duration;
 
-    let request_body = ShowRequest {
-        name: name.to_string(),
-        verbose,
-    };
+    /// Synchronously sends a request to the `show` endpoint to retrieve detailed information about a model.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - An optional reference to a `Ollama` instance.
+    /// * `name` - The name of the model to retrieve information about.
+    /// * `verbose` - An optional boolean flag to request more detailed information.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `ShowResponse` with the model details, or an error if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama::api::sync::show;
+    /// use ollama::prelude::Ollama;
+    ///
+    /// let ollama = Ollama::new().with_host("http://0.0.0.0").with_port(11434);
+    /// let response = show(Some(&ollama), "llama3.1:8b-instruct-q6_K", Some(true)).unwrap();
+    /// assert!(response.modelfile.contains("llama3.1"));
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the HTTP request fails or if the response cannot be deserialized.
+    pub fn show(client: Option<&Ollama>, name: &str, verbose: Option<bool>) -> Result<ShowResponse, OllamaError> {
+        Ok(show_with_meta(client, name, verbose)?.0)
+    }
 
-    let response = BlockingClient::new()
-        .post(&url)
-        .json(&request_body)
-        .send()?
-        .error_for_status()?;
+    /// Like [`show`], but also returns the response status and headers.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the HTTP request fails or if the response cannot be deserialized.
+    pub fn show_with_meta(
+        client: Option<&Ollama>,
+        name: &str,
+        verbose: Option<bool>,
+    ) -> Result<(ShowResponse, ResponseMeta), OllamaError> {
+        let request = ShowRequest {
+            name: name.to_string(),
+            verbose,
+        };
+        send(client, &request)
+    }
+
+    /// Like [`show`], but with a timeout for this call that overrides the client's; see [`Ollama::with_timeout`].
+    ///
+    /// The timeout is a total one, covering the whole request and response, not an idle timeout between reads.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the HTTP request fails or times out, or if the response cannot be deserialized.
+    pub fn show_with_timeout(
+        client: Option<&Ollama>,
+        name: &str,
+        verbose: Option<bool>,
+        timeout: Duration,
+    ) -> Result<ShowResponse, OllamaError> {
+        let client = Ollama::or_default(client).clone().with_timeout(timeout);
+        show(Some(&client), name, verbose)
+    }
+
+    /// Sends a [`ShowRequest`], e.g. one assembled with [`ShowRequest::builder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the HTTP request fails or if the response cannot be deserialized.
+    pub fn show_with(client: Option<&Ollama>, request: &ShowRequest) -> Result<ShowResponse, OllamaError> {
+        Ok(send(client, request)?.0)
+    }
 
-    let raw_body = response.text()?;
+    fn send(client: Option<&Ollama>, request: &ShowRequest) -> Result<(ShowResponse, ResponseMeta), OllamaError> {
+        traced!(tracing::info_span!("ollama.show", model = %request.name), || {
+            let client = Ollama::or_default(client);
+            let url = format!("{}{}", client.base_url(), SHOW_ENDPOINT);
 
-    #[cfg(feature = "logging")]
-    log::info!("Received response: {}", raw_body);
+            #[cfg(feature = "logging")]
+            log::info!("Sending synchronous request to URL: {url}");
 
-    let show_response: ShowResponse = serde_json::from_str(&raw_body)?;
-    Ok(show_response)
+            let body = request_body(request, client.normalizes_names())?;
+            let (raw_body, meta) = client.execute(RequestInfo::post(&url, &body))?;
+
+            #[cfg(feature = "logging")]
+            log::info!("Received response: {raw_body}");
+
+            let show_response: ShowResponse = serde_json::from_str(&raw_body)?;
+            Ok((show_response, meta))
+        })
+    }
 }
 
-/// Asynchronously sends a request to the `show` endpoint to retrieve detailed information about a model.
-///
-/// # Arguments
-///
-/// * `client` - An optional reference to a `Ollama` instance.
-/// * `name` - The name of the model to retrieve information about.
-/// * `verbose` - An optional boolean flag to request more detailed information.
-///
-/// # Returns
-///
-/// A `Result` containing a `ShowResponse` with the model details, or an error if the request fails.
-///
-/// # Examples
-///
-/// ```
-/// use ollama::prelude::*;
-/// use tokio;
-///
-/// #[tokio::main]
-/// async fn main() {
-///     let ollama = Ollama::new().with_host("http://0.0.0.0").with_port(11434);
-///     let response = show(Some(&ollama), "llama3.1:8b-instruct-q6_K", Some(true)).await.unwrap();
-///     assert!(response.modelfile.contains("llama3.1"));
-/// }
-/// ```
-///
-/// # Errors
-///
-/// This function returns an error if the HTTP request fails or if the response cannot be deserialized.
 #[cfg(feature = "async")]
-pub async fn show(client: Option<&Ollama>, name: &str, verbose: Option<bool>) -> Result<ShowResponse, Box<dyn Error>> {
-    let url = match client {
-        Some(client) => format!("{}{}", client.base_url(), SHOW_ENDPOINT),
-        None => format!("{}:{}{}", crate::constants::TEST_ENDPOINT_HOST, crate::constants::TEST_ENDPOINT_PORT, SHOW_ENDPOINT),
-    };
+pub mod r#async {
+    use super::{request_body, ShowRequest, ShowResponse, SHOW_ENDPOINT};
+    use crate::api::client::Ollama;
+    use crate::api::middleware::RequestInfo;
+    use crate::api::transport::ResponseMeta;
+    use crate::error::OllamaError;
+    use crate::trace::traced_async;
+    use std::time::Duration;
+
+    /// Asynchronously sends a request to the `show` endpoint to retrieve detailed information about a model.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - An optional reference to a `Ollama` instance.
+    /// * `name` - The name of the model to retrieve information about.
+    /// * `verbose` - An optional boolean flag to request more detailed information.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` containing a `ShowResponse` with the model details, or an error if the request fails.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use ollama::api::r#async::show;
+    /// use ollama::prelude::Ollama;
+    /// use tokio;
+    ///
+    /// #[tokio::main]
+    /// async fn main() {
+    ///     let ollama = Ollama::new().with_host("http://0.0.0.0").with_port(11434);
+    ///     let response = show(Some(&ollama), "llama3.1:8b-instruct-q6_K", Some(true)).await.unwrap();
+    ///     assert!(response.modelfile.contains("llama3.1"));
+    /// }
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the HTTP request fails or if the response cannot be deserialized.
+    pub async fn show(client: Option<&Ollama>, name: &str, verbose: Option<bool>) -> Result<ShowResponse, OllamaError> {
+        Ok(show_with_meta(client, name, verbose).await?.0)
+    }
 
-    #[cfg(feature = "logging")]
-    log::info!("Sending asynchronous request to URL: {}", url);
+    /// Like [`show`], but also returns the response status and headers.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the HTTP request fails or if the response cannot be deserialized.
+    pub async fn show_with_meta(
+        client: Option<&Ollama>,
+        name: &str,
+        verbose: Option<bool>,
+    ) -> Result<(ShowResponse, ResponseMeta), OllamaError> {
+        let request = ShowRequest {
+            name: name.to_string(),
+            verbose,
+        };
+        send(client, &request).await
+    }
 
-    let request_body = ShowRequest {
-        name: name.to_string(),
-        verbose,
-    };
+    /// Like [`show`], but with a timeout for this call that overrides the client's; see [`Ollama::with_timeout`].
+    ///
+    /// The timeout is a total one, covering the whole request and response, not an idle timeout between reads.
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the HTTP request fails or times out, or if the response cannot be deserialized.
+    pub async fn show_with_timeout(
+        client: Option<&Ollama>,
+        name: &str,
+        verbose: Option<bool>,
+        timeout: Duration,
+    ) -> Result<ShowResponse, OllamaError> {
+        let client = Ollama::or_default(client).clone().with_timeout(timeout);
+        show(Some(&client), name, verbose).await
+    }
 
-    let response = reqwest::Client::new()
-        .post(&url)
-        .json(&request_body)
-        .send()
-        .await?
-        .error_for_status()?;
+    /// Sends a [`ShowRequest`], e.g. one assembled with [`ShowRequest::builder`].
+    ///
+    /// # Errors
+    ///
+    /// This function returns an error if the HTTP request fails or if the response cannot be deserialized.
+    pub async fn show_with(client: Option<&Ollama>, request: &ShowRequest) -> Result<ShowResponse, OllamaError> {
+        Ok(send(client, request).await?.0)
+    }
 
-    let raw_body = response.text().await?;
+    async fn send(client: Option<&Ollama>, request: &ShowRequest) -> Result<(ShowResponse, ResponseMeta), OllamaError> {
+        traced_async!(tracing::info_span!("ollama.show", model = %request.name), async {
+            let client = Ollama::or_default(client);
+            let url = format!("{}{}", client.base_url(), SHOW_ENDPOINT);
 
-    #[cfg(feature = "logging")]
-    log::info!("Received response: {}", raw_body);
+            #[cfg(feature = "logging")]
+            log::info!("Sending asynchronous request to URL: {url}");
 
-    let show_response: ShowResponse = serde_json::from_str(&raw_body)?;
-    Ok(show_response)
+            let body = request_body(request, client.normalizes_names())?;
+            let (raw_body, meta) = client.execute_async(RequestInfo::post(&url, &body)).await?;
+
+            #[cfg(feature = "logging")]
+            log::info!("Received response: {raw_body}");
+
+            let show_response: ShowResponse = serde_json::from_str(&raw_body)?;
+            Ok((show_response, meta))
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::constants::TEST_ENDPOINT_HOST;
-    use crate::constants::TEST_ENDPOINT_PORT;
+    use crate::api::client::Ollama;
+    use crate::api::transport::MockTransport;
+    use crate::constants::{TEST_SHOW_MINIMAL_RESPONSE, TEST_SHOW_NULL_FAMILIES_RESPONSE, TEST_SHOW_RESPONSE};
+    use std::sync::Arc;
 
-    #[cfg(not(feature = "async"))]
+    #[cfg(feature = "sync")]
     #[test]
     fn test_show_sync_with_client() {
-        let client = Ollama::new().with_host(TEST_ENDPOINT_HOST).with_port(TEST_ENDPOINT_PORT);
-        let result = show(Some(&client), "llama3.1:8b-instruct-q6_K", Some(true));
+        let mock = Arc::new(MockTransport::new().with_response(SHOW_ENDPOINT, TEST_SHOW_RESPONSE));
+        let client = Ollama::new().with_transport(mock.clone());
+        let result = sync::show(Some(&client), "llama3.1:8b-instruct-q6_K", Some(true));
 
         match result {
             Ok(response) => {
                 assert!(response.modelfile.contains("llama3.1"));
+                assert_eq!(response.architecture(), Some("llama"));
+                assert_eq!(response.context_length(), Some(131_072));
+                assert_eq!(
+                    mock.requests()[0].body,
+                    Some(serde_json::json!({ "name": "llama3.1:8b-instruct-q6_K", "verbose": true }))
+                );
             }
             Err(e) => {
                 eprintln!("Error: {}", e);
@@ -172,12 +433,19 @@ mod tests {
     #[cfg(feature = "async")]
     #[tokio::test]
     async fn test_show_async_with_client() {
-        let client = Ollama::new().with_host(TEST_ENDPOINT_HOST).with_port(TEST_ENDPOINT_PORT);
-        let result = show(Some(&client), "llama3.1:8b-instruct-q6_K", Some(true)).await;
+        let mock = Arc::new(MockTransport::new().with_response(SHOW_ENDPOINT, TEST_SHOW_RESPONSE));
+        let client = Ollama::new().with_transport(mock.clone());
+        let result = r#async::show(Some(&client), "llama3.1:8b-instruct-q6_K", Some(true)).await;
 
         match result {
             Ok(response) => {
                 assert!(response.modelfile.contains("llama3.1"));
+                assert_eq!(response.architecture(), Some("llama"));
+                assert_eq!(response.context_length(), Some(131_072));
+                assert_eq!(
+                    mock.requests()[0].body,
+                    Some(serde_json::json!({ "name": "llama3.1:8b-instruct-q6_K", "verbose": true }))
+                );
             }
             Err(e) => {
                 eprintln!("Error: {}", e);
@@ -185,4 +453,165 @@ mod tests {
             }
         }
     }
-}
\ No newline at end of file
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_show_with_timeout_sync() {
+        use crate::error::OllamaError;
+        use std::time::Duration;
+
+        // Accepts connections through the backlog but never responds.
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = Ollama::new().try_with_host(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+
+        let error = sync::show_with_timeout(Some(&client), "llama3.1", None, Duration::from_millis(200)).unwrap_err();
+        assert!(matches!(error, OllamaError::Http(error) if error.is_timeout()));
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_show_with_timeout_async() {
+        use crate::error::OllamaError;
+        use std::time::Duration;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = Ollama::new().try_with_host(&format!("http://{}", listener.local_addr().unwrap())).unwrap();
+
+        let error = r#async::show_with_timeout(Some(&client), "llama3.1", None, Duration::from_millis(200))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, OllamaError::Http(error) if error.is_timeout()));
+    }
+
+    #[test]
+    fn test_show_response_missing_parameters() {
+        let raw = r#"{
+            "modelfile": "FROM llama3.1",
+            "template": "{{ .Prompt }}",
+            "details": { "family": "llama" }
+        }"#;
+
+        let response: ShowResponse = serde_json::from_str(raw).unwrap();
+        assert_eq!(response.parameters, "");
+        assert_eq!(response.template, "{{ .Prompt }}");
+        assert!(response.model_info.is_none());
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_show_with_request_builder() {
+        let mock = Arc::new(MockTransport::new().with_response(SHOW_ENDPOINT, TEST_SHOW_RESPONSE));
+        let client = Ollama::new().with_transport(mock.clone());
+        let request = ShowRequest::builder().name("llama3.1:8b-instruct-q6_K").verbose(true);
+
+        let response = sync::show_with(Some(&client), &request).unwrap();
+        assert_eq!(response.architecture(), Some("llama"));
+        assert_eq!(
+            mock.requests()[0].body,
+            Some(serde_json::json!({ "name": "llama3.1:8b-instruct-q6_K", "verbose": true }))
+        );
+    }
+
+    #[test]
+    fn test_show_request_from_partial_model() {
+        let mut model = PartialModel::new("llama3.1:latest");
+        model.model = "llama3.1:8b-instruct-q6_K".to_string();
+
+        let request = ShowRequest::from(&model);
+        assert_eq!(request, ShowRequest::new("llama3.1:8b-instruct-q6_K").verbose(true));
+    }
+
+    #[test]
+    fn test_dry_run() {
+        let request = ShowRequest::new("llama3.1").verbose(true);
+        assert_eq!(request.to_json_body().unwrap(), r#"{"name":"llama3.1","verbose":true}"#);
+
+        let mock = Arc::new(MockTransport::new());
+        let client = Ollama::new().with_transport(mock.clone());
+        assert_eq!(client.dry_run(&request).unwrap(), r#"{"name":"llama3.1:latest","verbose":true}"#);
+        assert!(mock.requests().is_empty());
+    }
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("llama3.1"), "llama3.1:latest");
+        assert_eq!(normalize_name("llama3.1:8b"), "llama3.1:8b");
+        assert_eq!(normalize_name("library/llama3.1"), "library/llama3.1:latest");
+        assert_eq!(normalize_name("registry.local:5000/team/model"), "registry.local:5000/team/model:latest");
+        assert_eq!(normalize_name("model@sha256:abc"), "model@sha256:abc");
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_show_normalizes_names() {
+        let mock = Arc::new(MockTransport::new().with_response(SHOW_ENDPOINT, TEST_SHOW_RESPONSE));
+        let client = Ollama::new().with_transport(mock.clone());
+        sync::show(Some(&client), "llama3.1", None).unwrap();
+        sync::show(Some(&client.with_normalize_names(false)), "llama3.1", None).unwrap();
+
+        let requests = mock.requests();
+        assert_eq!(requests[0].body, Some(serde_json::json!({ "name": "llama3.1:latest", "verbose": null })));
+        assert_eq!(requests[1].body, Some(serde_json::json!({ "name": "llama3.1", "verbose": null })));
+    }
+
+    #[test]
+    fn test_parameters_map() {
+        let response: ShowResponse = serde_json::from_str(TEST_SHOW_RESPONSE).unwrap();
+        let parameters = response.parameters_map();
+        assert_eq!(parameters.len(), 1);
+        assert_eq!(parameters["stop"], ["<|start_header_id|>", "<|end_header_id|>", "<|eot_id|>"]);
+
+        let raw = r#"{"parameters":"temperature                    0.7\n\nstop \"User: \\\"\"\nstop \"a\\nb\"\nnum_ctx 8192","details":{}}"#;
+        let response: ShowResponse = serde_json::from_str(raw).unwrap();
+        let parameters = response.parameters_map();
+        assert_eq!(parameters["temperature"], ["0.7"]);
+        assert_eq!(parameters["num_ctx"], ["8192"]);
+        assert_eq!(parameters["stop"], ["User: \"", "a\nb"]);
+    }
+
+    #[test]
+    fn test_show_response_golden_verbose() {
+        let response: ShowResponse = serde_json::from_str(TEST_SHOW_RESPONSE).unwrap();
+
+        assert!(response.modelfile.starts_with("# Modelfile generated by"));
+        assert!(response.parameters.contains("<|eot_id|>"));
+        assert_eq!(
+            response.details,
+            ModelDetails {
+                parent_model: Some(String::new()),
+                format: Some("gguf".to_string()),
+                family: Some("llama".to_string()),
+                families: Some(vec!["llama".to_string()]),
+                parameter_size: Some("8.0B".to_string()),
+                quantization_level: Some("Q6_K".to_string()),
+            }
+        );
+        let info = response.info().unwrap();
+        assert_eq!(info.parameter_count(), Some(8_030_261_248));
+        assert_eq!(info.embedding_length(), Some(4096));
+        assert_eq!(info.block_count(), Some(32));
+    }
+
+    #[test]
+    fn test_show_response_golden_minimal() {
+        let response: ShowResponse = serde_json::from_str(TEST_SHOW_MINIMAL_RESPONSE).unwrap();
+
+        assert_eq!(response.modelfile, "");
+        assert_eq!(response.template, "");
+        assert_eq!(response.details.parent_model, None);
+        assert_eq!(response.details.families, None);
+        assert_eq!(response.details.family.as_deref(), Some("gemma"));
+        assert_eq!(response.details.quantization_level.as_deref(), Some("Q4_0"));
+        assert!(response.info().is_none());
+    }
+
+    #[test]
+    fn test_show_response_golden_null_families() {
+        let response: ShowResponse = serde_json::from_str(TEST_SHOW_NULL_FAMILIES_RESPONSE).unwrap();
+
+        assert_eq!(response.details.family.as_deref(), Some("phi2"));
+        assert_eq!(response.details.families, None);
+        assert_eq!(response.details.parameter_size.as_deref(), Some("3B"));
+        assert_eq!(response.template, "{{ .Prompt }}");
+    }
+}
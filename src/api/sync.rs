@@ -0,0 +1,4 @@
+//! The blocking API, available with the `sync` feature.
+
+pub use crate::api::list::sync::{list, list_lenient, list_with_meta};
+pub use crate::api::show::sync::{show, show_with, show_with_meta, show_with_timeout};
@@ -0,0 +1,826 @@
+use crate::constants::REQUEST_ID_HEADER;
+use crate::error::OllamaError;
+use reqwest::header::HeaderMap;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex, OnceLock, PoisonError};
+use std::time::Duration;
+
+#[cfg(all(unix, feature = "unix-socket"))]
+use std::path::PathBuf;
+
+#[cfg(feature = "sync")]
+use std::sync::Condvar;
+
+#[cfg(feature = "async")]
+use std::future::Future;
+#[cfg(feature = "async")]
+use std::pin::Pin;
+
+/// The future returned by the [`AsyncTransport`] methods.
+#[cfg(feature = "async")]
+pub type TransportFuture<'a> = Pin<Box<dyn Future<Output = Result<String, OllamaError>> + Send + 'a>>;
+
+/// The future returned by the [`AsyncTransport`] `*_with_meta` methods.
+#[cfg(feature = "async")]
+pub type TransportMetaFuture<'a> = Pin<Box<dyn Future<Output = Result<(String, ResponseMeta), OllamaError>> + Send + 'a>>;
+
+/// The status and headers of a successful response.
+#[derive(Clone, Debug)]
+pub struct ResponseMeta {
+    pub status: u16,
+    pub headers: HeaderMap,
+    /// The `X-Request-Id` sent with the request, if request ids are enabled.
+    pub request_id: Option<String>,
+}
+
+impl Default for ResponseMeta {
+    /// A `200 OK` with no headers, used by transports that don't expose response metadata.
+    fn default() -> Self {
+        Self {
+            status: 200,
+            headers: HeaderMap::new(),
+            request_id: None,
+        }
+    }
+}
+
+/// The blocking HTTP layer used by the synchronous API.
+///
+/// Each method takes a fully qualified URL and returns the raw response body.
+/// The default implementation is [`ReqwestTransport`]; [`MockTransport`] serves
+/// canned responses so code can be tested without a running server.
+#[cfg(feature = "sync")]
+pub trait SyncTransport: Send + Sync {
+    /// Sends a `GET` request to `url`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the server returns an error status.
+    fn get(&self, url: &str) -> Result<String, OllamaError>;
+
+    /// Sends a `POST` request to `url` with `body` as JSON.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the server returns an error status.
+    fn post_json(&self, url: &str, body: &serde_json::Value) -> Result<String, OllamaError>;
+
+    /// Sends a `GET` request to `url`, also returning the response status and headers.
+    ///
+    /// The default implementation calls [`SyncTransport::get`] and reports a default [`ResponseMeta`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the server returns an error status.
+    fn get_with_meta(&self, url: &str) -> Result<(String, ResponseMeta), OllamaError> {
+        Ok((self.get(url)?, ResponseMeta::default()))
+    }
+
+    /// Sends a `POST` request to `url` with `body` as JSON, also returning the response status and headers.
+    ///
+    /// The default implementation calls [`SyncTransport::post_json`] and reports a default [`ResponseMeta`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the request fails or the server returns an error status.
+    fn post_json_with_meta(&self, url: &str, body: &serde_json::Value) -> Result<(String, ResponseMeta), OllamaError> {
+        Ok((self.post_json(url, body)?, ResponseMeta::default()))
+    }
+}
+
+/// The asynchronous HTTP layer used by the async API.
+///
+/// Each method takes a fully qualified URL and resolves to the raw response body.
+/// The default implementation is [`ReqwestTransport`]; [`MockTransport`] serves
+/// canned responses so code can be tested without a running server.
+#[cfg(feature = "async")]
+pub trait AsyncTransport: Send + Sync {
+    /// Sends a `GET` request to `url`.
+    ///
+    /// The returned future resolves to an error if the request fails or the
+    /// server returns an error status.
+    fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a>;
+
+    /// Sends a `POST` request to `url` with `body` as JSON.
+    ///
+    /// The returned future resolves to an error if the request fails or the
+    /// server returns an error status.
+    fn post_json<'a>(&'a self, url: &'a str, body: &'a serde_json::Value) -> TransportFuture<'a>;
+
+    /// Sends a `GET` request to `url`, also returning the response status and headers.
+    ///
+    /// The default implementation calls [`AsyncTransport::get`] and reports a default [`ResponseMeta`].
+    fn get_with_meta<'a>(&'a self, url: &'a str) -> TransportMetaFuture<'a> {
+        Box::pin(async move { Ok((self.get(url).await?, ResponseMeta::default())) })
+    }
+
+    /// Sends a `POST` request to `url` with `body` as JSON, also returning the response status and headers.
+    ///
+    /// The default implementation calls [`AsyncTransport::post_json`] and reports a default [`ResponseMeta`].
+    fn post_json_with_meta<'a>(&'a self, url: &'a str, body: &'a serde_json::Value) -> TransportMetaFuture<'a> {
+        Box::pin(async move { Ok((self.post_json(url, body).await?, ResponseMeta::default())) })
+    }
+}
+
+/// A transport usable by every enabled API flavor.
+///
+/// This is implemented automatically for any type implementing [`SyncTransport`]
+/// and/or [`AsyncTransport`], depending on which of the `sync` and `async`
+/// features are enabled.
+#[cfg(all(feature = "sync", feature = "async"))]
+pub trait Transport: SyncTransport + AsyncTransport {}
+#[cfg(all(feature = "sync", feature = "async"))]
+impl<T: SyncTransport + AsyncTransport> Transport for T {}
+
+/// A transport usable by every enabled API flavor.
+#[cfg(all(feature = "sync", not(feature = "async")))]
+pub trait Transport: SyncTransport {}
+#[cfg(all(feature = "sync", not(feature = "async")))]
+impl<T: SyncTransport> Transport for T {}
+
+/// A transport usable by every enabled API flavor.
+#[cfg(all(feature = "async", not(feature = "sync")))]
+pub trait Transport: AsyncTransport {}
+#[cfg(all(feature = "async", not(feature = "sync")))]
+impl<T: AsyncTransport> Transport for T {}
+
+/// How the default `reqwest` clients pick a proxy.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum ProxyConfig {
+    /// Use the proxy given by the `HTTP_PROXY`/`HTTPS_PROXY`/`NO_PROXY` environment variables, if any.
+    #[default]
+    System,
+    /// Send all requests through the proxy at this URL.
+    All(String),
+    /// Connect directly, ignoring the environment.
+    Disabled,
+}
+
+/// Which HTTP version the default `reqwest` clients speak.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HttpVersion {
+    /// Negotiate the version, e.g. via ALPN for `https`.
+    #[default]
+    Negotiate,
+    /// Speak only HTTP/1.1.
+    Http1Only,
+    /// Speak HTTP/2 without negotiating it first.
+    Http2PriorKnowledge,
+}
+
+/// Connection settings applied when building the default `reqwest` clients.
+// Each flag mirrors one independent builder option.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Clone, Debug, Default)]
+pub struct HttpConfig {
+    pub(crate) pool_max_idle_per_host: Option<usize>,
+    pub(crate) pool_idle_timeout: Option<Duration>,
+    pub(crate) proxy: ProxyConfig,
+    pub(crate) http_version: HttpVersion,
+    pub(crate) bearer_token: Option<String>,
+    pub(crate) timeout: Option<Duration>,
+    pub(crate) headers: HeaderMap,
+    pub(crate) concurrency_limit: Option<Arc<ConcurrencyLimit>>,
+    pub(crate) lossy_decoding: bool,
+    /// Returns error responses like successful ones instead of as [`OllamaError::Api`].
+    pub(crate) ignore_status: bool,
+    #[cfg(feature = "uuid")]
+    pub(crate) request_ids: bool,
+    #[cfg(any(feature = "default-tls", feature = "native-tls", feature = "rustls-tls"))]
+    pub(crate) accept_invalid_certs: bool,
+    #[cfg(any(feature = "default-tls", feature = "native-tls", feature = "rustls-tls"))]
+    pub(crate) root_certificates: Vec<reqwest::Certificate>,
+    #[cfg(all(unix, feature = "unix-socket"))]
+    pub(crate) unix_socket: Option<PathBuf>,
+}
+
+/// Limits the number of requests a client has in flight at once.
+///
+/// Blocking and asynchronous requests draw from separate pools of `max` permits each.
+#[derive(Debug)]
+pub struct ConcurrencyLimit {
+    #[cfg(feature = "sync")]
+    available: Mutex<usize>,
+    #[cfg(feature = "sync")]
+    released: Condvar,
+    #[cfg(feature = "async")]
+    semaphore: tokio::sync::Semaphore,
+}
+
+impl ConcurrencyLimit {
+    /// Creates a limit of `max` concurrent requests per flavor, at least one.
+    pub(crate) fn new(max: usize) -> Self {
+        let max = max.max(1);
+        Self {
+            #[cfg(feature = "sync")]
+            available: Mutex::new(max),
+            #[cfg(feature = "sync")]
+            released: Condvar::new(),
+            #[cfg(feature = "async")]
+            semaphore: tokio::sync::Semaphore::new(max),
+        }
+    }
+
+    /// Blocks until a permit is available and returns it; the permit is released when dropped.
+    #[cfg(feature = "sync")]
+    fn acquire_blocking(&self) -> BlockingPermit<'_> {
+        let mut available = self.available.lock().unwrap_or_else(PoisonError::into_inner);
+        while *available == 0 {
+            available = self.released.wait(available).unwrap_or_else(PoisonError::into_inner);
+        }
+        *available -= 1;
+        drop(available);
+        BlockingPermit(self)
+    }
+
+    /// Waits until a permit is available and returns it; the permit is released when dropped.
+    #[cfg(feature = "async")]
+    async fn acquire(&self) -> Option<tokio::sync::SemaphorePermit<'_>> {
+        // The semaphore is never closed, so this always returns a permit.
+        self.semaphore.acquire().await.ok()
+    }
+}
+
+/// A permit of a [`ConcurrencyLimit`] held by a blocking request.
+#[cfg(feature = "sync")]
+struct BlockingPermit<'a>(&'a ConcurrencyLimit);
+
+#[cfg(feature = "sync")]
+impl Drop for BlockingPermit<'_> {
+    fn drop(&mut self) {
+        *self.0.available.lock().unwrap_or_else(PoisonError::into_inner) += 1;
+        self.0.released.notify_one();
+    }
+}
+
+/// Applies an [`HttpConfig`] to a blocking or async `reqwest::ClientBuilder`.
+macro_rules! configure_builder {
+    ($builder:expr, $config:expr) => {{
+        let mut builder = $builder;
+        if let Some(max) = $config.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(timeout) = $config.pool_idle_timeout {
+            builder = builder.pool_idle_timeout(timeout);
+        }
+        match &$config.proxy {
+            ProxyConfig::System => {}
+            ProxyConfig::All(url) => builder = builder.proxy(reqwest::Proxy::all(url.as_str())?),
+            ProxyConfig::Disabled => builder = builder.no_proxy(),
+        }
+        match $config.http_version {
+            HttpVersion::Negotiate => {}
+            HttpVersion::Http1Only => builder = builder.http1_only(),
+            HttpVersion::Http2PriorKnowledge => builder = builder.http2_prior_knowledge(),
+        }
+        if let Some(token) = &$config.bearer_token {
+            let mut value = reqwest::header::HeaderValue::from_str(&format!("Bearer {token}"))?;
+            value.set_sensitive(true);
+            builder = builder.default_headers(HeaderMap::from_iter([(reqwest::header::AUTHORIZATION, value)]));
+        }
+        #[cfg(any(feature = "default-tls", feature = "native-tls", feature = "rustls-tls"))]
+        {
+            builder = builder.danger_accept_invalid_certs($config.accept_invalid_certs);
+            for certificate in &$config.root_certificates {
+                builder = builder.add_root_certificate(certificate.clone());
+            }
+        }
+        #[cfg(all(unix, feature = "unix-socket"))]
+        if let Some(path) = &$config.unix_socket {
+            builder = builder.unix_socket(path.clone());
+        }
+        builder
+    }};
+}
+
+impl HttpConfig {
+    /// Generates a random (version 4) UUID for the next request if request ids are enabled.
+    #[cfg(feature = "uuid")]
+    fn request_id(&self) -> Option<String> {
+        self.request_ids.then(|| uuid::Uuid::new_v4().to_string())
+    }
+
+    /// Generates an id for the next request if request ids are enabled.
+    #[cfg(not(feature = "uuid"))]
+    #[allow(clippy::unused_self)]
+    const fn request_id(&self) -> Option<String> {
+        None
+    }
+
+    /// Decodes a response body as UTF-8, replacing invalid sequences if lossy decoding is enabled.
+    fn decode(&self, body: Vec<u8>) -> Result<String, OllamaError> {
+        match String::from_utf8(body) {
+            Ok(body) => Ok(body),
+            Err(error) if self.lossy_decoding => {
+                #[cfg(feature = "logging")]
+                log::warn!("Replacing invalid UTF-8 in response body: {error}");
+                Ok(String::from_utf8_lossy(error.as_bytes()).into_owned())
+            }
+            Err(error) => Err(error.into()),
+        }
+    }
+
+    /// Builds a blocking `reqwest` client with these settings.
+    #[cfg(feature = "sync")]
+    fn build_blocking(&self) -> Result<reqwest::blocking::Client, OllamaError> {
+        Ok(configure_builder!(reqwest::blocking::Client::builder(), self).build()?)
+    }
+
+    /// Builds an async `reqwest` client with these settings.
+    #[cfg(feature = "async")]
+    fn build_async(&self) -> Result<reqwest::Client, OllamaError> {
+        Ok(configure_builder!(reqwest::Client::builder(), self).build()?)
+    }
+}
+
+/// The default transport, backed by `reqwest`.
+///
+/// The underlying clients are built on first use, so configuration errors surface
+/// from the first request rather than from the client builder. Clones share the
+/// clients, and with them the connection pool, even if those are built after cloning.
+/// Note that a blocking client, once built, must not be dropped from within an async runtime.
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    config: HttpConfig,
+    #[cfg(feature = "sync")]
+    blocking_client: Arc<OnceLock<reqwest::blocking::Client>>,
+    #[cfg(feature = "async")]
+    client: Arc<OnceLock<reqwest::Client>>,
+}
+
+impl ReqwestTransport {
+    /// Creates a transport whose clients are built on first use with default settings.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a transport whose clients are built from `config` on first use.
+    pub(crate) fn from_config(config: HttpConfig) -> Self {
+        Self {
+            config,
+            ..Self::default()
+        }
+    }
+
+    /// Returns a transport with `config` that shares the clients, and with them the connection pool,
+    /// whether they were built before or are built after this call.
+    ///
+    /// Only settings applied per request, such as the timeout, take effect on shared clients.
+    pub(crate) fn with_config(&self, config: HttpConfig) -> Self {
+        Self {
+            config,
+            ..self.clone()
+        }
+    }
+
+    /// Sends asynchronous requests through the given `reqwest` client.
+    #[cfg(feature = "async")]
+    #[must_use]
+    pub fn with_client(self, client: reqwest::Client) -> Self {
+        Self {
+            client: Arc::new(OnceLock::from(client)),
+            ..self
+        }
+    }
+
+    /// Sends blocking requests through the given `reqwest` blocking client.
+    #[cfg(feature = "sync")]
+    #[must_use]
+    pub fn with_blocking_client(self, client: reqwest::blocking::Client) -> Self {
+        Self {
+            blocking_client: Arc::new(OnceLock::from(client)),
+            ..self
+        }
+    }
+
+    /// Returns the async `reqwest` client, building it on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client cannot be built, e.g. because the TLS backend fails to initialize.
+    #[cfg(feature = "async")]
+    pub fn client(&self) -> Result<&reqwest::Client, OllamaError> {
+        if let Some(client) = self.client.get() {
+            return Ok(client);
+        }
+        let client = self.config.build_async()?;
+        Ok(self.client.get_or_init(|| client))
+    }
+
+    /// Returns the blocking `reqwest` client, building it on first use.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the client cannot be built, e.g. because the TLS backend fails to initialize.
+    #[cfg(feature = "sync")]
+    pub fn blocking_client(&self) -> Result<&reqwest::blocking::Client, OllamaError> {
+        if let Some(client) = self.blocking_client.get() {
+            return Ok(client);
+        }
+        let client = self.config.build_blocking()?;
+        Ok(self.blocking_client.get_or_init(|| client))
+    }
+}
+
+impl ReqwestTransport {
+    /// Sends a blocking request, tagging it with a request id if enabled.
+    #[cfg(feature = "sync")]
+    fn send_blocking(&self, request: reqwest::blocking::RequestBuilder) -> Result<(String, ResponseMeta), OllamaError> {
+        let request_id = self.config.request_id();
+        let request = match &request_id {
+            Some(id) => request.header(REQUEST_ID_HEADER, id),
+            None => request,
+        };
+        let request = match self.config.timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        };
+        let request = request.headers(self.config.headers.clone());
+        let _permit = self.config.concurrency_limit.as_deref().map(ConcurrencyLimit::acquire_blocking);
+        let response = request.send()?;
+        let meta = ResponseMeta {
+            status: response.status().as_u16(),
+            headers: response.headers().clone(),
+            request_id,
+        };
+        let body = self.config.decode(response.bytes()?.to_vec())?;
+        if self.config.ignore_status {
+            return Ok((body, meta));
+        }
+        check_status(body, meta)
+    }
+
+    /// Sends an async request, tagging it with a request id if enabled.
+    #[cfg(feature = "async")]
+    async fn send_async(&self, request: reqwest::RequestBuilder) -> Result<(String, ResponseMeta), OllamaError> {
+        let request_id = self.config.request_id();
+        let request = match &request_id {
+            Some(id) => request.header(REQUEST_ID_HEADER, id),
+            None => request,
+        };
+        let request = match self.config.timeout {
+            Some(timeout) => request.timeout(timeout),
+            None => request,
+        };
+        let request = request.headers(self.config.headers.clone());
+        let _permit = match &self.config.concurrency_limit {
+            Some(limit) => limit.acquire().await,
+            None => None,
+        };
+        let response = request.send().await?;
+        let meta = ResponseMeta {
+            status: response.status().as_u16(),
+            headers: response.headers().clone(),
+            request_id,
+        };
+        let body = self.config.decode(response.bytes().await?.to_vec())?;
+        if self.config.ignore_status {
+            return Ok((body, meta));
+        }
+        check_status(body, meta)
+    }
+}
+
+#[cfg(feature = "sync")]
+impl SyncTransport for ReqwestTransport {
+    fn get(&self, url: &str) -> Result<String, OllamaError> {
+        Ok(SyncTransport::get_with_meta(self, url)?.0)
+    }
+
+    fn post_json(&self, url: &str, body: &serde_json::Value) -> Result<String, OllamaError> {
+        Ok(SyncTransport::post_json_with_meta(self, url, body)?.0)
+    }
+
+    fn get_with_meta(&self, url: &str) -> Result<(String, ResponseMeta), OllamaError> {
+        self.send_blocking(self.blocking_client()?.get(url))
+    }
+
+    fn post_json_with_meta(&self, url: &str, body: &serde_json::Value) -> Result<(String, ResponseMeta), OllamaError> {
+        self.send_blocking(self.blocking_client()?.post(url).json(body))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for ReqwestTransport {
+    fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+        Box::pin(async move { Ok(AsyncTransport::get_with_meta(self, url).await?.0) })
+    }
+
+    fn post_json<'a>(&'a self, url: &'a str, body: &'a serde_json::Value) -> TransportFuture<'a> {
+        Box::pin(async move { Ok(AsyncTransport::post_json_with_meta(self, url, body).await?.0) })
+    }
+
+    fn get_with_meta<'a>(&'a self, url: &'a str) -> TransportMetaFuture<'a> {
+        Box::pin(async move { self.send_async(self.client()?.get(url)).await })
+    }
+
+    fn post_json_with_meta<'a>(&'a self, url: &'a str, body: &'a serde_json::Value) -> TransportMetaFuture<'a> {
+        Box::pin(async move { self.send_async(self.client()?.post(url).json(body)).await })
+    }
+}
+
+/// Returns `body` and `meta` on success, or an [`OllamaError::Api`] for an error status.
+fn check_status(body: String, meta: ResponseMeta) -> Result<(String, ResponseMeta), OllamaError> {
+    if (200..300).contains(&meta.status) {
+        Ok((body, meta))
+    } else {
+        Err(OllamaError::api(meta.status, &body, meta.request_id))
+    }
+}
+
+/// A request recorded by [`MockTransport`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MockRequest {
+    pub method: String,
+    pub url: String,
+    pub body: Option<serde_json::Value>,
+}
+
+/// A transport that serves canned responses instead of talking to a server.
+///
+/// Responses are registered per endpoint path (e.g. `/api/tags`) and matched
+/// against the end of the request URL. Every request is recorded so tests can
+/// assert on what was sent. It implements both [`SyncTransport`] and [`AsyncTransport`].
+///
+/// # Examples
+///
+/// ```
+/// use ollama::prelude::*;
+/// use std::sync::Arc;
+///
+/// let mock = Arc::new(MockTransport::new().with_response("/api/tags", r#"{"models":[]}"#));
+/// let client = Ollama::new().with_transport(mock.clone());
+/// ```
+#[derive(Debug, Default)]
+pub struct MockTransport {
+    responses: Mutex<HashMap<String, String>>,
+    requests: Mutex<Vec<MockRequest>>,
+}
+
+impl MockTransport {
+    /// Creates a mock transport with no registered responses.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers `body` as the response for requests to `path`.
+    #[must_use]
+    pub fn with_response(self, path: &str, body: &str) -> Self {
+        self.responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(path.to_string(), body.to_string());
+        self
+    }
+
+    /// Returns the requests sent through this transport so far, oldest first.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    fn respond(&self, method: &str, url: &str, body: Option<&serde_json::Value>) -> Result<String, OllamaError> {
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(MockRequest {
+                method: method.to_string(),
+                url: url.to_string(),
+                body: body.cloned(),
+            });
+
+        self.responses
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .iter()
+            .find(|(path, _)| url.ends_with(path.as_str()))
+            .map(|(_, response)| response.clone())
+            .ok_or_else(|| OllamaError::Transport(format!("no mock response registered for {url}")))
+    }
+}
+
+#[cfg(feature = "sync")]
+impl SyncTransport for MockTransport {
+    fn get(&self, url: &str) -> Result<String, OllamaError> {
+        self.respond("GET", url, None)
+    }
+
+    fn post_json(&self, url: &str, body: &serde_json::Value) -> Result<String, OllamaError> {
+        self.respond("POST", url, Some(body))
+    }
+}
+
+#[cfg(feature = "async")]
+impl AsyncTransport for MockTransport {
+    fn get<'a>(&'a self, url: &'a str) -> TransportFuture<'a> {
+        let result = self.respond("GET", url, None);
+        Box::pin(async move { result })
+    }
+
+    fn post_json<'a>(&'a self, url: &'a str, body: &'a serde_json::Value) -> TransportFuture<'a> {
+        let result = self.respond("POST", url, Some(body));
+        Box::pin(async move { result })
+    }
+}
+
+/// Serves a single response on a local port, returning the base URL and a handle yielding the raw request.
+#[cfg(all(test, feature = "sync"))]
+pub fn serve_once(status: &str, body: impl AsRef<[u8]> + Send + 'static) -> (String, std::thread::JoinHandle<String>) {
+    use std::io::{Read, Write};
+    use std::net::TcpListener;
+
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let status = status.to_string();
+    let server = std::thread::spawn(move || {
+        let (mut stream, _) = listener.accept().unwrap();
+        let mut buffer = [0; 1024];
+        let read = stream.read(&mut buffer).unwrap();
+        let body = body.as_ref();
+        write!(stream, "HTTP/1.1 {status}\r\ncontent-length: {}\r\n\r\n", body.len()).unwrap();
+        stream.write_all(body).unwrap();
+        String::from_utf8_lossy(&buffer[..read]).to_lowercase()
+    });
+    (url, server)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_with_config_shares_unbuilt_clients() {
+        let transport = ReqwestTransport::new();
+        let derived = transport.with_config(HttpConfig {
+            timeout: Some(Duration::from_secs(1)),
+            ..HttpConfig::default()
+        });
+
+        #[cfg(feature = "async")]
+        assert!(std::ptr::eq(derived.client().unwrap(), transport.client().unwrap()));
+        #[cfg(feature = "sync")]
+        assert!(std::ptr::eq(derived.blocking_client().unwrap(), transport.blocking_client().unwrap()));
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_error_status_returns_api_error() {
+        let (url, server) = serve_once("404 Not Found", r#"{"error":"model 'missing' not found"}"#);
+        let transport = ReqwestTransport::new();
+        let error = SyncTransport::get(&transport, &format!("{url}/api/show")).unwrap_err();
+        server.join().unwrap();
+
+        match error {
+            OllamaError::Api {
+                status,
+                message,
+                request_id,
+            } => {
+                assert_eq!(status, 404);
+                assert_eq!(message, "model 'missing' not found");
+                assert_eq!(request_id, None);
+            }
+            other => panic!("expected an API error, got {other:?}"),
+        }
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_request_headers_merge_with_client_headers() {
+        let (url, server) = serve_once("200 OK", "{}");
+        let transport = ReqwestTransport::from_config(HttpConfig {
+            bearer_token: Some("secret".to_string()),
+            headers: HeaderMap::from_iter([(
+                reqwest::header::HeaderName::from_static("idempotency-key"),
+                reqwest::header::HeaderValue::from_static("abc123"),
+            )]),
+            ..HttpConfig::default()
+        });
+        SyncTransport::get(&transport, &url).unwrap();
+        let request = server.join().unwrap();
+
+        assert!(request.contains("authorization: bearer secret"));
+        assert!(request.contains("idempotency-key: abc123"));
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_ignore_status_returns_error_body() {
+        let (url, server) = serve_once("404 Not Found", r#"{"error":"model 'missing' not found"}"#);
+        let transport = ReqwestTransport::from_config(HttpConfig {
+            ignore_status: true,
+            ..HttpConfig::default()
+        });
+        let (body, meta) = SyncTransport::get_with_meta(&transport, &url).unwrap();
+        server.join().unwrap();
+
+        assert_eq!(meta.status, 404);
+        assert_eq!(body, r#"{"error":"model 'missing' not found"}"#);
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_invalid_utf8_is_rejected_unless_lossy() {
+        let body = b"{\"error\":\"bad \xff byte\"}";
+        let (url, server) = serve_once("200 OK", body);
+        let error = SyncTransport::get(&ReqwestTransport::new(), &url).unwrap_err();
+        server.join().unwrap();
+        assert!(matches!(error, OllamaError::InvalidUtf8(_)));
+
+        let (url, server) = serve_once("200 OK", body);
+        let transport = ReqwestTransport::from_config(HttpConfig {
+            lossy_decoding: true,
+            ..HttpConfig::default()
+        });
+        let decoded = SyncTransport::get(&transport, &url).unwrap();
+        server.join().unwrap();
+        assert_eq!(decoded, "{\"error\":\"bad \u{fffd} byte\"}");
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_concurrency_limit_blocks_until_released() {
+        use std::sync::mpsc;
+
+        let limit = Arc::new(ConcurrencyLimit::new(1));
+        let permit = limit.acquire_blocking();
+        let (sender, receiver) = mpsc::channel();
+        let waiter = {
+            let limit = limit.clone();
+            std::thread::spawn(move || {
+                let _permit = limit.acquire_blocking();
+                sender.send(()).unwrap();
+            })
+        };
+
+        assert!(receiver.recv_timeout(Duration::from_millis(100)).is_err());
+        drop(permit);
+        assert!(receiver.recv_timeout(Duration::from_secs(5)).is_ok());
+        waiter.join().unwrap();
+        assert_eq!(*limit.available.lock().unwrap(), 1);
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_concurrency_limit_async() {
+        let limit = ConcurrencyLimit::new(2);
+        let first = limit.acquire().await;
+        let _second = limit.acquire().await;
+        assert_eq!(limit.semaphore.available_permits(), 0);
+        drop(first);
+        assert_eq!(limit.semaphore.available_permits(), 1);
+        assert_eq!(ConcurrencyLimit::new(0).semaphore.available_permits(), 1);
+    }
+
+    #[cfg(all(feature = "sync", feature = "uuid"))]
+    #[test]
+    fn test_request_id_is_sent_and_reported() {
+        let (url, server) = serve_once("404 Not Found", r#"{"error":"model 'missing' not found"}"#);
+        let transport = ReqwestTransport::from_config(HttpConfig {
+            request_ids: true,
+            ..HttpConfig::default()
+        });
+        let error = SyncTransport::get(&transport, &format!("{url}/api/show")).unwrap_err();
+        let request = server.join().unwrap();
+
+        let OllamaError::Api { request_id: Some(id), .. } = error else {
+            panic!("expected an API error with a request id");
+        };
+        assert_eq!(id.len(), 36);
+        assert_eq!(id.as_bytes()[14], b'4');
+        assert!(request.contains(&format!("x-request-id: {id}")));
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_mock_transport_records_requests_sync() {
+        let mock = MockTransport::new().with_response("/api/show", "{}");
+        let body = serde_json::json!({ "name": "llama3.1" });
+
+        assert_eq!(SyncTransport::post_json(&mock, "http://0.0.0.0:11434/api/show", &body).unwrap(), "{}");
+        assert!(SyncTransport::get(&mock, "http://0.0.0.0:11434/api/tags").is_err());
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].body, Some(body));
+        assert_eq!(requests[1].url, "http://0.0.0.0:11434/api/tags");
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_mock_transport_records_requests_async() {
+        let mock = MockTransport::new().with_response("/api/show", "{}");
+        let body = serde_json::json!({ "name": "llama3.1" });
+
+        assert_eq!(AsyncTransport::post_json(&mock, "http://0.0.0.0:11434/api/show", &body).await.unwrap(), "{}");
+        assert!(AsyncTransport::get(&mock, "http://0.0.0.0:11434/api/tags").await.is_err());
+
+        let requests = mock.requests();
+        assert_eq!(requests.len(), 2);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].body, Some(body));
+        assert_eq!(requests[1].url, "http://0.0.0.0:11434/api/tags");
+    }
+}
@@ -1,4 +1,16 @@
-pub(crate) const API_TAGS_ENDPOINT: &str = "/api/tags";
-pub(crate) const SHOW_ENDPOINT: &str = "/api/show";
-pub(crate) const TEST_ENDPOINT_HOST: &str = "http://0.0.0.0";
-pub(crate) const TEST_ENDPOINT_PORT: u16 = 11434;
\ No newline at end of file
+use std::time::Duration;
+
+pub const API_TAGS_ENDPOINT: &str = "/api/tags";
+pub const SHOW_ENDPOINT: &str = "/api/show";
+pub const PING_TIMEOUT: Duration = Duration::from_secs(2);
+pub const REQUEST_ID_HEADER: &str = "X-Request-Id";
+#[cfg(test)]
+pub const TEST_TAGS_RESPONSE: &str = include_str!("../tests/fixtures/tags.json");
+#[cfg(test)]
+pub const TEST_SHOW_RESPONSE: &str = include_str!("../tests/fixtures/show.json");
+#[cfg(test)]
+pub const TEST_EMPTY_TAGS_RESPONSE: &str = include_str!("../tests/fixtures/tags_empty.json");
+#[cfg(test)]
+pub const TEST_SHOW_MINIMAL_RESPONSE: &str = include_str!("../tests/fixtures/show_minimal.json");
+#[cfg(test)]
+pub const TEST_SHOW_NULL_FAMILIES_RESPONSE: &str = include_str!("../tests/fixtures/show_null_families.json");
@@ -0,0 +1,121 @@
+use thiserror::Error;
+
+/// Errors that can occur while talking to the Ollama API.
+#[derive(Error, Debug)]
+pub enum OllamaError {
+    /// The HTTP request failed, e.g. because the connection was refused or timed out.
+    ///
+    /// Error statuses are reported as [`OllamaError::Api`] instead.
+    #[error("HTTP request failed: {0}")]
+    Http(#[from] reqwest::Error),
+
+    /// A request or response body could not be (de)serialized.
+    #[error("JSON (de)serialization failed: {0}")]
+    Json(#[from] serde_json::Error),
+
+    /// A configured header value, such as a bearer token, contains invalid characters.
+    #[error("invalid header value: {0}")]
+    InvalidHeader(#[from] reqwest::header::InvalidHeaderValue),
+
+    /// The configured host is not a valid `http` or `https` URL.
+    #[error("invalid host `{host}`: {reason}")]
+    InvalidHost { host: String, reason: String },
+
+    /// The server responded with an error status.
+    ///
+    /// `message` is the `error` field of the response body when present, otherwise the raw body.
+    /// `request_id` is the `X-Request-Id` sent with the request, if request ids are enabled.
+    #[error("API error (status {status}): {message}")]
+    Api {
+        status: u16,
+        message: String,
+        request_id: Option<String>,
+    },
+
+    /// A response body is not valid UTF-8; see [`Ollama::with_lossy_decoding`](crate::api::client::Ollama::with_lossy_decoding).
+    #[error("response body is not valid UTF-8: {0}")]
+    InvalidUtf8(#[from] std::string::FromUtf8Error),
+
+    /// A custom [`Transport`](crate::api::transport::Transport) failed.
+    #[error("transport error: {0}")]
+    Transport(String),
+}
+
+impl OllamaError {
+    /// Returns `true` if retrying the request may succeed: timeouts, connection failures,
+    /// and the statuses `429`, `500`, `502`, `503` and `504`.
+    #[must_use]
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::Http(error) => error.is_timeout() || error.is_connect(),
+            Self::Api { status, .. } => matches!(status, 429 | 500 | 502 | 503 | 504),
+            _ => false,
+        }
+    }
+
+    /// Returns `true` if the server responded with `404`, e.g. because a model is not installed.
+    #[must_use]
+    pub const fn is_not_found(&self) -> bool {
+        matches!(self, Self::Api { status: 404, .. })
+    }
+
+    /// Builds an [`OllamaError::Api`] from an error response body.
+    pub(crate) fn api(status: u16, body: &str, request_id: Option<String>) -> Self {
+        #[derive(serde::Deserialize)]
+        struct ErrorBody {
+            error: String,
+        }
+
+        let message = serde_json::from_str::<ErrorBody>(body).map_or_else(|_| body.to_string(), |body| body.error);
+        Self::Api {
+            status,
+            message,
+            request_id,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_api_error_predicates() {
+        let error = |status| OllamaError::api(status, r#"{"error":"boom"}"#, None);
+
+        for status in [429, 500, 502, 503, 504] {
+            assert!(error(status).is_retryable(), "{status}");
+            assert!(!error(status).is_not_found(), "{status}");
+        }
+        assert!(error(404).is_not_found());
+        assert!(!error(404).is_retryable());
+        assert!(!error(400).is_retryable());
+    }
+
+    #[test]
+    fn test_other_error_predicates() {
+        let errors = [
+            OllamaError::Json(serde_json::from_str::<u8>("x").unwrap_err()),
+            OllamaError::InvalidHost {
+                host: "ftp://host".to_string(),
+                reason: "unsupported scheme".to_string(),
+            },
+            OllamaError::Transport("closed".to_string()),
+        ];
+        for error in errors {
+            assert!(!error.is_retryable(), "{error}");
+            assert!(!error.is_not_found(), "{error}");
+        }
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_connection_error_is_retryable() {
+        // Bind and release a port so nothing is listening on it.
+        let address = std::net::TcpListener::bind("127.0.0.1:0").unwrap().local_addr().unwrap();
+        let error: OllamaError = reqwest::blocking::get(format!("http://{address}")).unwrap_err().into();
+
+        assert!(error.is_retryable());
+        assert!(!error.is_not_found());
+    }
+}
@@ -1,13 +1,47 @@
-pub(crate) mod api;
+#[cfg(not(any(feature = "sync", feature = "async")))]
+compile_error!("at least one of the `sync` and `async` features must be enabled");
+
+pub mod api;
 pub(crate) mod constants;
+pub mod error;
 pub mod structs;
 
 #[cfg(feature = "logging")]
 pub(crate) mod logging;
 
 pub mod prelude;
+pub(crate) mod trace;
 
 // Re-export structs through the prelude
+pub use structs::digest::Digest;
+pub use structs::model::DetailsSummary;
 pub use structs::model::Model;
 pub use structs::model::ModelDetails;
+pub use structs::modelfile::Modelfile;
+pub use structs::modelinfo::ModelInfo;
 pub use structs::partialmodel::PartialModel;
+pub use error::OllamaError;
+
+// The public types are shared across threads and tasks, e.g. an `Ollama` in an `Arc`;
+// keep them `Send + Sync` so a change cannot silently break that.
+const _: fn() = || {
+    const fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<api::cache::CachingClient>();
+    assert_send_sync::<api::client::Ollama>();
+    assert_send_sync::<api::transport::ReqwestTransport>();
+    assert_send_sync::<api::transport::MockTransport>();
+    assert_send_sync::<api::transport::ResponseMeta>();
+    assert_send_sync::<api::list::ModelsResponse>();
+    assert_send_sync::<api::list::DeserializeError>();
+    assert_send_sync::<api::show::ShowRequest>();
+    assert_send_sync::<api::show::ShowResponse>();
+    assert_send_sync::<OllamaError>();
+    assert_send_sync::<Digest>();
+    assert_send_sync::<DetailsSummary>();
+    assert_send_sync::<Model>();
+    assert_send_sync::<ModelDetails>();
+    assert_send_sync::<Modelfile>();
+    assert_send_sync::<ModelInfo<'static>>();
+    assert_send_sync::<PartialModel>();
+};
@@ -1,23 +1,45 @@
 #[cfg(feature = "logging")]
-use dotenv::dotenv;
+use log::info;
+
+#[cfg(all(feature = "logging", not(feature = "tracing")))]
+use env_logger::Env;
 
 #[cfg(feature = "logging")]
-use log::{info, LevelFilter};
+use std::sync::Once;
 
 #[cfg(feature = "logging")]
-use std::env;
+static INIT_LOGGER: Once = Once::new();
 
+/// Initializes the global logger.
+///
+/// With the `tracing` feature, `log` records are forwarded to the current `tracing`
+/// subscriber through `tracing-log` instead of being printed by `env_logger`.
+/// Only one logger can be installed per process, so repeated calls
+/// (e.g. from constructing several clients) are no-ops after the first.
 #[cfg(feature = "logging")]
 pub fn init_logger() {
-    dotenv().ok();
-
-    if env::var("RUST_LOG").is_err() {
-        env::set_var("RUST_LOG", "info");
-    }
+    INIT_LOGGER.call_once(|| {
+        // Another logger may already be installed by the host application.
+        #[cfg(feature = "tracing")]
+        let installed = tracing_log::LogTracer::init().is_ok();
+        // Fall back to `info` when `RUST_LOG` is unset, without touching the
+        // host process environment.
+        #[cfg(not(feature = "tracing"))]
+        let installed = env_logger::Builder::from_env(Env::default().default_filter_or("info")).try_init().is_ok();
+        if installed {
+            info!("Logger initialized");
+        }
+    });
+}
 
-    env_logger::Builder::from_default_env()
-        .filter(None, LevelFilter::Info)
-        .init();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    info!("Logger initialized");
+    #[cfg(feature = "logging")]
+    #[test]
+    fn test_init_logger_is_idempotent() {
+        init_logger();
+        init_logger();
+    }
 }
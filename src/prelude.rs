@@ -1,8 +1,55 @@
-pub use crate::api::client::Ollama;
-pub use crate::api::list::list;
-pub use crate::api::show::show;
+pub use crate::api::cache::CachingClient;
+pub use crate::api::client::{default_client, set_default_client, Ollama};
+#[cfg(feature = "async")]
+pub use crate::api::r#async::{list, show};
+#[cfg(all(feature = "sync", not(feature = "async")))]
+pub use crate::api::sync::{list, show};
+pub use crate::api::list::{DeserializeError, SortBy, SortOrder};
+pub use crate::api::middleware::{Middleware, RequestInfo};
+pub use crate::api::show::{ShowRequest, ShowResponse};
+pub use crate::api::transport::{MockRequest, MockTransport, ReqwestTransport, ResponseMeta, Transport};
+#[cfg(feature = "async")]
+pub use crate::api::transport::{AsyncTransport, TransportFuture, TransportMetaFuture};
+#[cfg(feature = "sync")]
+pub use crate::api::transport::SyncTransport;
+pub use crate::error::OllamaError;
 
 // Re-export the structs to simplify usage
+pub use crate::structs::digest::Digest;
+pub use crate::structs::model::DetailsSummary;
 pub use crate::structs::model::Model;
 pub use crate::structs::model::ModelDetails;
+pub use crate::structs::modelfile::Modelfile;
+pub use crate::structs::modelinfo::ModelInfo;
 pub use crate::structs::partialmodel::PartialModel;
+
+/// The prelude with the blocking [`list`](crate::api::sync::list) and [`show`](crate::api::sync::show),
+/// regardless of whether the `async` feature is enabled.
+///
+/// ```no_run
+/// use ollama::prelude::sync::*;
+///
+/// let models = list(None).unwrap();
+/// ```
+#[cfg(feature = "sync")]
+pub mod sync {
+    pub use super::*;
+    pub use crate::api::sync::{list, show};
+}
+
+/// The prelude with the asynchronous `list` and `show` from `ollama::api::async`,
+/// regardless of whether the `sync` feature is enabled.
+///
+/// ```no_run
+/// use ollama::prelude::r#async::*;
+///
+/// #[tokio::main]
+/// async fn main() {
+///     let models = list(None).await.unwrap();
+/// }
+/// ```
+#[cfg(feature = "async")]
+pub mod r#async {
+    pub use super::*;
+    pub use crate::api::r#async::{list, show};
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Deserializer, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+/// A SHA-256 content digest, as used to identify models and blobs.
+///
+/// Accepts either a bare 64-character hex string or one prefixed with `sha256:`,
+/// and serializes back in the form it was given. An unknown digest is `None` where
+/// one is optional, e.g. [`PartialModel::digest`](crate::structs::partialmodel::PartialModel::digest).
+#[derive(Serialize, Debug, Clone, PartialEq, Eq, Hash)]
+#[serde(transparent)]
+pub struct Digest(String);
+
+/// The error returned when parsing a malformed [`Digest`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidDigest(String);
+
+impl fmt::Display for InvalidDigest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid digest `{}`: expected 64 hex characters, optionally prefixed with `sha256:`", self.0)
+    }
+}
+
+impl std::error::Error for InvalidDigest {}
+
+impl Digest {
+    /// Returns the 64-character hex part of the digest.
+    #[must_use]
+    pub fn as_hex(&self) -> &str {
+        self.0.strip_prefix("sha256:").unwrap_or(&self.0)
+    }
+}
+
+impl FromStr for Digest {
+    type Err = InvalidDigest;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = s.strip_prefix("sha256:").unwrap_or(s);
+        if hex.len() == 64 && hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            Ok(Self(s.to_string()))
+        } else {
+            Err(InvalidDigest(s.to_string()))
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Digest {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let raw = String::deserialize(deserializer)?;
+        raw.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+/// Deserializes an optional digest, reading a missing, `null` or empty digest as `None`.
+///
+/// Use with `#[serde(default, deserialize_with = "...")]`, since the API may report an unknown digest as `""`.
+pub(crate) fn deserialize_optional<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<Digest>, D::Error> {
+    match Option::<String>::deserialize(deserializer)?.as_deref() {
+        None | Some("") => Ok(None),
+        Some(raw) => raw.parse().map(Some).map_err(serde::de::Error::custom),
+    }
+}
+
+impl fmt::Display for Digest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl AsRef<str> for Digest {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEX: &str = "a5864ede0c4971b7eb12c14b27069902e8bb32691d997a55ac71c4831cdd01e2";
+
+    #[test]
+    fn test_digest_parsing() {
+        let bare: Digest = HEX.parse().unwrap();
+        let prefixed: Digest = format!("sha256:{HEX}").parse().unwrap();
+        assert_eq!(bare.as_hex(), HEX);
+        assert_eq!(prefixed.as_hex(), HEX);
+        assert_eq!(prefixed.to_string(), format!("sha256:{HEX}"));
+
+        assert!("".parse::<Digest>().is_err());
+        assert!("sha256:".parse::<Digest>().is_err());
+        assert!("sha256:abc".parse::<Digest>().is_err());
+        assert!(format!("md5:{HEX}").parse::<Digest>().is_err());
+        assert!(HEX.replace('a', "g").parse::<Digest>().is_err());
+    }
+
+    #[test]
+    fn test_digest_serde_round_trip() {
+        let json = format!("\"sha256:{HEX}\"");
+        let digest: Digest = serde_json::from_str(&json).unwrap();
+        assert_eq!(serde_json::to_string(&digest).unwrap(), json);
+        assert!(serde_json::from_str::<Digest>("\"not a digest\"").is_err());
+    }
+}
@@ -1,2 +1,5 @@
+pub mod digest;
 pub mod model;
-pub mod partialmodel;
\ No newline at end of file
+pub mod modelfile;
+pub mod modelinfo;
+pub mod partialmodel;
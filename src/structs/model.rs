@@ -1,9 +1,27 @@
 use serde::{Deserialize, Serialize};
 use serde_json::{self, Result as JsonResult};
+use std::fmt;
+use std::io::{Read, Write};
 use crate::api::show::ShowResponse;
+use crate::structs::digest::{self, Digest};
+use crate::structs::modelinfo::ModelInfo;
+use crate::structs::partialmodel::size_human;
 
 /// Represents the details of a model, including metadata such as format, family, and size.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+///
+/// New fields may be added as the API evolves; outside this crate, obtain values by
+/// deserialization or with [`ModelDetails::builder`].
+///
+/// # Examples
+///
+/// ```
+/// use ollama::prelude::ModelDetails;
+///
+/// let details = ModelDetails::builder().family("llama").parameter_size("8.0B").quantization_level("Q6_K");
+/// assert_eq!(details.to_string(), "family=llama, 8.0B, Q6_K");
+/// ```
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct ModelDetails {
     pub parent_model: Option<String>,
     pub format: Option<String>,
@@ -13,14 +31,133 @@ pub struct ModelDetails {
     pub quantization_level: Option<String>,
 }
 
+impl ModelDetails {
+    /// Creates empty details to be filled in with the builder methods.
+    #[must_use]
+    pub fn builder() -> Self {
+        Self::default()
+    }
+
+    /// Sets the model this one was derived from.
+    #[must_use]
+    pub fn parent_model(mut self, parent_model: impl Into<String>) -> Self {
+        self.parent_model = Some(parent_model.into());
+        self
+    }
+
+    /// Sets the file format, e.g. `gguf`.
+    #[must_use]
+    pub fn format(mut self, format: impl Into<String>) -> Self {
+        self.format = Some(format.into());
+        self
+    }
+
+    /// Sets the primary model family, e.g. `llama`.
+    #[must_use]
+    pub fn family(mut self, family: impl Into<String>) -> Self {
+        self.family = Some(family.into());
+        self
+    }
+
+    /// Sets the model families, replacing any previously set.
+    ///
+    /// Named `with_families` because [`ModelDetails::families`] is the accessor.
+    #[must_use]
+    pub fn with_families<I>(mut self, families: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        self.families = Some(families.into_iter().map(Into::into).collect());
+        self
+    }
+
+    /// Sets the parameter count as reported by the server, e.g. `8.0B`.
+    #[must_use]
+    pub fn parameter_size(mut self, parameter_size: impl Into<String>) -> Self {
+        self.parameter_size = Some(parameter_size.into());
+        self
+    }
+
+    /// Sets the quantization level, e.g. `Q6_K`.
+    #[must_use]
+    pub fn quantization_level(mut self, quantization_level: impl Into<String>) -> Self {
+        self.quantization_level = Some(quantization_level.into());
+        self
+    }
+
+    /// Returns the model's families, or an empty slice if the server sent `null`, `[]` or omitted them.
+    #[must_use]
+    pub fn families(&self) -> &[String] {
+        self.families.as_deref().unwrap_or_default()
+    }
+}
+
+impl fmt::Display for ModelDetails {
+    /// Formats the details as e.g. `family=llama, 8B, Q6_K`, skipping missing values.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let family = self.family.as_deref().filter(|family| !family.is_empty()).map(|family| format!("family={family}"));
+        let parts: Vec<_> = family
+            .into_iter()
+            .chain(self.parameter_size.clone())
+            .chain(self.quantization_level.clone())
+            .filter(|part| !part.is_empty())
+            .collect();
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+/// A compact report of a model's size, quantization and capabilities, e.g. for a model picker.
+///
+/// Obtained from [`Model::details_summary`] or [`ShowResponse::details_summary`]. The
+/// capability flags are heuristics over the template, families and `model_info`, not a
+/// guarantee: `supports_tools` is set when the template references `.Tools`, and
+/// `is_multimodal` when the model has a `clip`/`mllama` family or vision metadata.
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct DetailsSummary {
+    pub family: Option<String>,
+    pub parameter_size: Option<String>,
+    pub quantization: Option<String>,
+    pub context_length: Option<u64>,
+    pub supports_tools: bool,
+    pub is_multimodal: bool,
+}
+
+impl DetailsSummary {
+    /// Builds the summary from the parts shared by [`Model`] and [`ShowResponse`].
+    pub(crate) fn new(details: &ModelDetails, model_info: Option<&serde_json::Value>, template: &str) -> Self {
+        let info = model_info.and_then(ModelInfo::new);
+        let vision_family = details.families().iter().any(|family| matches!(family.as_str(), "clip" | "mllama"));
+        let vision_info = model_info
+            .and_then(serde_json::Value::as_object)
+            .is_some_and(|entries| entries.keys().any(|key| key.starts_with("clip.") || key.contains(".vision.")));
+        Self {
+            family: details.family.clone(),
+            parameter_size: details.parameter_size.clone(),
+            quantization: details.quantization_level.clone(),
+            context_length: info.and_then(|info| info.context_length()),
+            supports_tools: template.contains(".Tools"),
+            is_multimodal: vision_family || vision_info,
+        }
+    }
+}
+
 /// Represents a model returned by the API, including its metadata and associated details.
+///
+/// New fields may be added as the API evolves; outside this crate, obtain values from
+/// [`PartialModel::to_model`](crate::structs::partialmodel::PartialModel::to_model),
+/// [`Model::from_show_response`] or [`Model::from_json`].
 #[derive(Serialize, Deserialize, Debug, PartialEq)]
+#[non_exhaustive]
 pub struct Model {
     pub name: String,
     pub model: String,
     pub modified_at: String,
     pub size: u64,
-    pub digest: String,
+    /// The model's digest, or `None` if it is unknown, e.g. for a model built from a `show` response alone.
+    #[serde(default, deserialize_with = "digest::deserialize_optional", skip_serializing_if = "Option::is_none")]
+    pub digest: Option<Digest>,
     pub parameters: Option<String>,
     pub template: Option<String>,
     pub details: ModelDetails,
@@ -29,13 +166,17 @@ pub struct Model {
 
 impl Model {
     /// Creates a new instance of `Model` from a `ShowResponse`.
+    ///
+    /// The `show` endpoint does not return the model's name, modification time, size or digest,
+    /// so these are left empty; [`PartialModel::to_model`](crate::structs::partialmodel::PartialModel::to_model)
+    /// fills them in from the `list` entry.
     pub fn from_show_response(response: ShowResponse) -> Self {
         Model {
-            name: response.details.family.clone().unwrap_or_default(),
-            model: response.details.family.clone().unwrap_or_default(),
-            modified_at: response.details.parameter_size.clone().unwrap_or_default(),
-            size: response.details.parameter_size.clone().unwrap_or_default().parse().unwrap_or(0),
-            digest: response.details.quantization_level.clone().unwrap_or_default(),
+            name: String::new(),
+            model: String::new(),
+            modified_at: String::new(),
+            size: 0,
+            digest: None,
             parameters: Some(response.parameters),
             template: Some(response.template),
             details: response.details,
@@ -43,8 +184,179 @@ impl Model {
         }
     }
 
+    /// Returns a compact summary of the model's details and capabilities; see [`DetailsSummary`].
+    #[must_use]
+    pub fn details_summary(&self) -> DetailsSummary {
+        DetailsSummary::new(&self.details, self.model_info.as_ref(), self.template.as_deref().unwrap_or_default())
+    }
+
+    /// Returns `true` if both models have the same weights, comparing only `digest` and `details`.
+    ///
+    /// Unlike `==`, this ignores fields that change without the weights changing, such as
+    /// `modified_at` and `model_info`. Models without a digest, e.g. built by
+    /// [`Model::from_show_response`] alone, are never considered the same.
+    #[must_use]
+    pub fn same_weights(&self, other: &Self) -> bool {
+        self.digest.is_some() && self.digest == other.digest && self.details == other.details
+    }
+
     /// Serializes the `Model` instance to a JSON string.
     pub fn json(&self) -> JsonResult<String> {
         serde_json::to_string(self)
     }
+
+    /// Serializes the `Model` instance as JSON into the given writer.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if serialization fails or the writer returns an I/O error.
+    pub fn to_writer<W: Write>(&self, writer: W) -> JsonResult<()> {
+        serde_json::to_writer(writer, self)
+    }
+
+    /// Deserializes a `Model` from a JSON string, e.g. one produced by [`Model::json`].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the string is not a valid JSON representation of a `Model`.
+    pub fn from_json(json: &str) -> JsonResult<Self> {
+        serde_json::from_str(json)
+    }
+
+    /// Deserializes a `Model` from a reader containing JSON, e.g. a cache file.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails or the data is not a valid JSON representation of a `Model`.
+    pub fn from_reader<R: Read>(reader: R) -> JsonResult<Self> {
+        serde_json::from_reader(reader)
+    }
+}
+
+impl fmt::Display for Model {
+    /// Formats the model as e.g. `llama3.1:8b (2.3 GB, family=llama, 8B, Q6_K)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}", self.name, size_human(self.size))?;
+        let details = self.details.to_string();
+        if !details.is_empty() {
+            write!(f, ", {details}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::TEST_SHOW_RESPONSE;
+
+    #[test]
+    fn test_from_show_response_mapping() {
+        let response: ShowResponse = serde_json::from_str(TEST_SHOW_RESPONSE).unwrap();
+        let model = Model::from_show_response(response);
+
+        assert_eq!(model.name, "");
+        assert_eq!(model.size, 0);
+        assert_eq!(model.digest, None);
+        assert_eq!(model.details.family.as_deref(), Some("llama"));
+        assert!(model.template.unwrap().contains(".Prompt"));
+        assert!(model.model_info.is_some());
+    }
+
+    #[test]
+    fn test_families_accessor() {
+        for raw in [r#"{"families":null}"#, "{}", r#"{"families":[]}"#] {
+            let details: ModelDetails = serde_json::from_str(raw).unwrap();
+            assert!(details.families().is_empty(), "{raw}");
+        }
+
+        let details: ModelDetails = serde_json::from_str(r#"{"families":["llama","clip"]}"#).unwrap();
+        assert_eq!(details.families(), ["llama", "clip"]);
+    }
+
+    #[test]
+    fn test_details_builder() {
+        let response: ShowResponse = serde_json::from_str(TEST_SHOW_RESPONSE).unwrap();
+        let details = ModelDetails::builder()
+            .parent_model("")
+            .format("gguf")
+            .family("llama")
+            .with_families(["llama"])
+            .parameter_size("8.0B")
+            .quantization_level("Q6_K");
+
+        assert_eq!(details, response.details);
+        assert_eq!(ModelDetails::builder(), serde_json::from_str("{}").unwrap());
+    }
+
+    #[test]
+    fn test_display() {
+        let response: ShowResponse = serde_json::from_str(TEST_SHOW_RESPONSE).unwrap();
+        let mut model = Model::from_show_response(response);
+        model.name = "llama3.1:8b".to_string();
+        model.size = 6_596_006_954;
+
+        assert_eq!(model.details.to_string(), "family=llama, 8.0B, Q6_K");
+        assert_eq!(model.to_string(), "llama3.1:8b (6.1 GB, family=llama, 8.0B, Q6_K)");
+
+        let details: ModelDetails = serde_json::from_str(r#"{"family":"","quantization_level":"Q4_0"}"#).unwrap();
+        assert_eq!(details.to_string(), "Q4_0");
+    }
+
+    #[test]
+    fn test_details_summary() {
+        let response: ShowResponse = serde_json::from_str(TEST_SHOW_RESPONSE).unwrap();
+        let summary = response.details_summary();
+        assert_eq!(summary.family.as_deref(), Some("llama"));
+        assert_eq!(summary.parameter_size.as_deref(), Some("8.0B"));
+        assert_eq!(summary.quantization.as_deref(), Some("Q6_K"));
+        assert_eq!(summary.context_length, Some(131_072));
+        assert!(!summary.is_multimodal);
+        assert_eq!(Model::from_show_response(response).details_summary(), summary);
+
+        let response: ShowResponse = serde_json::from_str(
+            r#"{"template":"{{ if .Tools }}{{ .Tools }}{{ end }}","details":{"families":["llama","clip"]}}"#,
+        )
+        .unwrap();
+        let summary = response.details_summary();
+        assert!(summary.supports_tools);
+        assert!(summary.is_multimodal);
+        assert_eq!(summary.context_length, None);
+
+        let response: ShowResponse =
+            serde_json::from_str(r#"{"details":{},"model_info":{"mllama.vision.block_count":32}}"#).unwrap();
+        assert!(response.details_summary().is_multimodal);
+    }
+
+    #[test]
+    fn test_same_weights() {
+        let model = || {
+            let response: ShowResponse = serde_json::from_str(TEST_SHOW_RESPONSE).unwrap();
+            Model::from_show_response(response)
+        };
+        let (mut a, mut b) = (model(), model());
+        assert!(!a.same_weights(&b));
+
+        a.digest = "a5864ede0c4971b7eb12c14b27069902e8bb32691d997a55ac71c4831cdd01e2".parse().ok();
+        b.digest = a.digest.clone();
+        a.modified_at = "2024-08-26T13:02:58+01:00".to_string();
+        b.modified_at = "2024-09-01T08:00:00+01:00".to_string();
+        b.model_info = None;
+        assert_ne!(a, b);
+        assert!(a.same_weights(&b));
+
+        b.details.quantization_level = Some("Q4_0".to_string());
+        assert!(!a.same_weights(&b));
+    }
+
+    #[test]
+    fn test_model_json_round_trip() {
+        let response: ShowResponse = serde_json::from_str(TEST_SHOW_RESPONSE).unwrap();
+        let model = Model::from_show_response(response);
+
+        let mut buffer = Vec::new();
+        model.to_writer(&mut buffer).unwrap();
+        assert_eq!(Model::from_reader(buffer.as_slice()).unwrap(), model);
+        assert_eq!(Model::from_json(&model.json().unwrap()).unwrap(), model);
+    }
 }
@@ -0,0 +1,199 @@
+use std::borrow::Cow;
+use std::fmt;
+
+/// A builder for [Modelfile](https://github.com/ollama/ollama/blob/main/docs/modelfile.md) text,
+/// e.g. to customize a base model with a system prompt and parameters.
+///
+/// Directives are written in the order `FROM`, `PARAMETER`, `TEMPLATE`, `SYSTEM`, `MESSAGE`.
+/// `TEMPLATE` and `SYSTEM` are always wrapped in `"""`, so they may span several lines
+/// but must not contain `"""` themselves.
+///
+/// # Examples
+///
+/// ```
+/// use ollama::prelude::*;
+///
+/// let modelfile = Modelfile::from("llama3.1")
+///     .parameter("temperature", 0.2)
+///     .system("You are Mario from Super Mario Bros.")
+///     .build();
+/// assert_eq!(
+///     modelfile,
+///     "FROM llama3.1\nPARAMETER temperature 0.2\nSYSTEM \"\"\"You are Mario from Super Mario Bros.\"\"\"\n"
+/// );
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Modelfile {
+    from: String,
+    parameters: Vec<(String, String)>,
+    template: Option<String>,
+    system: Option<String>,
+    messages: Vec<(String, String)>,
+}
+
+impl Modelfile {
+    /// Starts a Modelfile based on `base`, a model name or a path to a GGUF file.
+    // Named after the `FROM` directive rather than implementing `From`.
+    #[allow(clippy::should_implement_trait)]
+    #[must_use]
+    pub fn from(base: impl Into<String>) -> Self {
+        Self {
+            from: base.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds a `PARAMETER` directive. May be repeated, e.g. for several `stop` sequences.
+    ///
+    /// Values containing whitespace, `"` or `#` are quoted, see [`quote_parameter`].
+    #[must_use]
+    pub fn parameter(mut self, key: impl Into<String>, value: impl fmt::Display) -> Self {
+        self.parameters.push((key.into(), value.to_string()));
+        self
+    }
+
+    /// Sets the `TEMPLATE` directive, replacing any previously set.
+    #[must_use]
+    pub fn template(mut self, template: impl Into<String>) -> Self {
+        self.template = Some(template.into());
+        self
+    }
+
+    /// Sets the `SYSTEM` directive, replacing any previously set.
+    #[must_use]
+    pub fn system(mut self, system: impl Into<String>) -> Self {
+        self.system = Some(system.into());
+        self
+    }
+
+    /// Adds a `MESSAGE` directive to the conversation history, with `role` one of `system`, `user` or `assistant`.
+    #[must_use]
+    pub fn message(mut self, role: impl Into<String>, content: impl Into<String>) -> Self {
+        self.messages.push((role.into(), content.into()));
+        self
+    }
+
+    /// Returns the Modelfile text.
+    #[must_use]
+    pub fn build(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl fmt::Display for Modelfile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "FROM {}", self.from)?;
+        for (key, value) in &self.parameters {
+            writeln!(f, "PARAMETER {key} {}", quote_parameter(value))?;
+        }
+        if let Some(template) = &self.template {
+            writeln!(f, "TEMPLATE \"\"\"{template}\"\"\"")?;
+        }
+        if let Some(system) = &self.system {
+            writeln!(f, "SYSTEM \"\"\"{system}\"\"\"")?;
+        }
+        for (role, content) in &self.messages {
+            if content.contains('\n') {
+                writeln!(f, "MESSAGE {role} \"\"\"{content}\"\"\"")?;
+            } else {
+                writeln!(f, "MESSAGE {role} {content}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Quotes a `PARAMETER` value if it contains whitespace, `"` or `#`, and returns it unchanged otherwise.
+///
+/// Quoted values are wrapped in `"`, with `\` and `"` escaped by a backslash and newlines written as `\n`,
+/// so that each directive stays on a single line.
+#[must_use]
+pub fn quote_parameter(value: &str) -> Cow<'_, str> {
+    if !value.contains(|c: char| c.is_whitespace() || c == '"' || c == '#') {
+        return Cow::Borrowed(value);
+    }
+    let mut quoted = String::with_capacity(value.len() + 2);
+    quoted.push('"');
+    for c in value.chars() {
+        match c {
+            '"' | '\\' => {
+                quoted.push('\\');
+                quoted.push(c);
+            }
+            '\n' => quoted.push_str("\\n"),
+            _ => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    Cow::Owned(quoted)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reads back a value written by [`quote_parameter`].
+    fn unquote_parameter(quoted: &str) -> String {
+        let Some(inner) = quoted.strip_prefix('"').and_then(|rest| rest.strip_suffix('"')) else {
+            return quoted.to_string();
+        };
+        let mut value = String::with_capacity(inner.len());
+        let mut chars = inner.chars();
+        while let Some(c) = chars.next() {
+            if c == '\\' {
+                match chars.next() {
+                    Some('n') => value.push('\n'),
+                    Some(escaped) => value.push(escaped),
+                    None => panic!("dangling escape in {quoted}"),
+                }
+            } else {
+                value.push(c);
+            }
+        }
+        value
+    }
+
+    #[test]
+    fn test_build_directives() {
+        let modelfile = Modelfile::from("llama3.1:8b")
+            .parameter("num_ctx", 4096)
+            .parameter("stop", "<|eot_id|>")
+            .parameter("stop", "User: ")
+            .template("{{ .System }}\n{{ .Prompt }}")
+            .system("Answer briefly.")
+            .message("user", "Is Toronto in Canada?")
+            .message("assistant", "yes");
+
+        assert_eq!(
+            modelfile.build(),
+            "FROM llama3.1:8b\n\
+             PARAMETER num_ctx 4096\n\
+             PARAMETER stop <|eot_id|>\n\
+             PARAMETER stop \"User: \"\n\
+             TEMPLATE \"\"\"{{ .System }}\n{{ .Prompt }}\"\"\"\n\
+             SYSTEM \"\"\"Answer briefly.\"\"\"\n\
+             MESSAGE user Is Toronto in Canada?\n\
+             MESSAGE assistant yes\n"
+        );
+    }
+
+    #[test]
+    fn test_multiline_message_is_quoted() {
+        let modelfile = Modelfile::from("./model.gguf").message("user", "line one\nline two");
+        assert_eq!(modelfile.build(), "FROM ./model.gguf\nMESSAGE user \"\"\"line one\nline two\"\"\"\n");
+    }
+
+    #[test]
+    fn test_quote_parameter_round_trip() {
+        assert_eq!(quote_parameter("<|eot_id|>"), "<|eot_id|>");
+        assert_eq!(quote_parameter("User: "), r#""User: ""#);
+        assert_eq!(quote_parameter(r#"say "hi""#), r#""say \"hi\"""#);
+        assert_eq!(quote_parameter("#end"), r##""#end""##);
+        assert_eq!(quote_parameter("\n\n"), r#""\n\n""#);
+
+        let values = ["0.2", "<|eot_id|>", "User: ", "\tindent", r#"say "hi""#, "#end", r"C:\models dir\", "a\nb"];
+        for value in values {
+            assert_eq!(unquote_parameter(&quote_parameter(value)), value, "{value:?}");
+        }
+    }
+}
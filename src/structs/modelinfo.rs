@@ -0,0 +1,81 @@
+use serde_json::{Map, Value};
+
+/// A typed view over the `model_info` metadata returned by a verbose `show` call.
+///
+/// Keys are GGUF metadata names such as `general.architecture` or
+/// `llama.context_length`; architecture-specific keys are looked up using the
+/// model's `general.architecture` as prefix. The raw value stays available via [`ModelInfo::get`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ModelInfo<'a> {
+    entries: &'a Map<String, Value>,
+}
+
+impl<'a> ModelInfo<'a> {
+    /// Wraps a raw `model_info` value, returning `None` if it is not a JSON object.
+    #[must_use]
+    pub fn new(value: &'a Value) -> Option<Self> {
+        value.as_object().map(|entries| Self { entries })
+    }
+
+    /// Returns the raw value stored under `key`.
+    #[must_use]
+    pub fn get(&self, key: &str) -> Option<&'a Value> {
+        self.entries.get(key)
+    }
+
+    /// Returns the model architecture, e.g. `llama`.
+    #[must_use]
+    pub fn architecture(&self) -> Option<&'a str> {
+        self.get("general.architecture")?.as_str()
+    }
+
+    /// Returns the total number of parameters.
+    #[must_use]
+    pub fn parameter_count(&self) -> Option<u64> {
+        self.get("general.parameter_count")?.as_u64()
+    }
+
+    /// Returns the maximum context length the model was trained with.
+    #[must_use]
+    pub fn context_length(&self) -> Option<u64> {
+        self.architecture_value("context_length")?.as_u64()
+    }
+
+    /// Returns the embedding length (hidden size).
+    #[must_use]
+    pub fn embedding_length(&self) -> Option<u64> {
+        self.architecture_value("embedding_length")?.as_u64()
+    }
+
+    /// Returns the number of transformer blocks.
+    #[must_use]
+    pub fn block_count(&self) -> Option<u64> {
+        self.architecture_value("block_count")?.as_u64()
+    }
+
+    /// Returns the value stored under `<architecture>.<suffix>`.
+    #[must_use]
+    pub fn architecture_value(&self, suffix: &str) -> Option<&'a Value> {
+        self.get(&format!("{}.{suffix}", self.architecture()?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::TEST_SHOW_RESPONSE;
+
+    #[test]
+    fn test_model_info_accessors() {
+        let response: Value = serde_json::from_str(TEST_SHOW_RESPONSE).unwrap();
+        let info = ModelInfo::new(&response["model_info"]).unwrap();
+
+        assert_eq!(info.architecture(), Some("llama"));
+        assert_eq!(info.context_length(), Some(131_072));
+        assert_eq!(info.embedding_length(), Some(4096));
+        assert_eq!(info.block_count(), Some(32));
+        assert_eq!(info.parameter_count(), Some(8_030_261_248));
+        assert_eq!(info.get("tokenizer.ggml.model"), Some(&Value::from("gpt2")));
+        assert!(ModelInfo::new(&Value::Null).is_none());
+    }
+}
@@ -1,20 +1,77 @@
 use serde::{Deserialize, Serialize};
+use std::fmt;
 use crate::api::client::Ollama;
-use crate::structs::model::Model;
-use std::error::Error;
-use crate::prelude::show;
+use crate::api::show::ShowResponse;
+use crate::structs::digest::{self, Digest};
+use crate::structs::model::{Model, ModelDetails};
+use crate::error::OllamaError;
 
 /// Represents a partial model returned by the `/api/tags` endpoint.
-#[derive(Serialize, Deserialize, Debug, PartialEq)]
+///
+/// New fields may be added as the API evolves, so outside this crate values are
+/// obtained from `list`, deserialization or [`PartialModel::new`].
+#[derive(Serialize, Deserialize, Debug, Default, Clone, PartialEq)]
+#[non_exhaustive]
 pub struct PartialModel {
     pub name: String,
     pub model: String,
     pub modified_at: String,
     pub size: u64,
-    pub digest: String,
+    /// The model's digest, or `None` if the server reported none or an empty one.
+    #[serde(default, deserialize_with = "digest::deserialize_optional", skip_serializing_if = "Option::is_none")]
+    pub digest: Option<Digest>,
+    pub details: Option<ModelDetails>,
 }
 
 impl PartialModel {
+    /// Creates a partial model referring to `name`, with the remaining fields empty.
+    ///
+    /// This is enough to call [`PartialModel::show`] or [`PartialModel::to_model`].
+    #[must_use]
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        Self {
+            model: name.clone(),
+            name,
+            ..Self::default()
+        }
+    }
+
+    /// Returns the size on disk in human-readable form, e.g. `2.3 GB`.
+    #[must_use]
+    pub fn size_human(&self) -> String {
+        size_human(self.size)
+    }
+
+    /// Retrieves detailed information about this model by calling the `show` API with `verbose: true`.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - An optional reference to a `Ollama` instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `show` request fails.
+    #[cfg(feature = "async")]
+    pub async fn show(&self, client: Option<&Ollama>) -> Result<ShowResponse, OllamaError> {
+        crate::api::r#async::show_with(client, &self.into()).await
+    }
+
+    /// Retrieves detailed information about this model by calling the `show` API with `verbose: true`,
+    /// blocking the current thread.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - An optional reference to a `Ollama` instance.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the `show` request fails.
+    #[cfg(feature = "sync")]
+    pub fn show_blocking(&self, client: Option<&Ollama>) -> Result<ShowResponse, OllamaError> {
+        crate::api::sync::show_with(client, &self.into())
+    }
+
     /// Converts a `PartialModel` to a full `Model` by calling the `show` API.
     ///
     /// # Arguments
@@ -25,34 +82,30 @@ impl PartialModel {
     ///
     /// A `Result` containing a `Model` instance or an error if the request fails.
     ///
+    /// # Errors
+    ///
+    /// Returns an error if the `show` request fails.
+    ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use ollama::prelude::*;
     /// use tokio; // Import the Tokio runtime for the async test
     ///
     /// #[tokio::main]
     /// async fn main() {
     ///     let client = Ollama::new().with_host("http://0.0.0.0").with_port(11434);
-    ///     let partial_model = PartialModel {
-    ///         name: "llama3.1:8b-instruct-q6_K".to_string(),
-    ///         model: "llama3.1:8b-instruct-q6_K".to_string(),
-    ///         modified_at: "2024-08-26T13:02:58.883873254+01:00".to_string(),
-    ///         size: 2490902249,
-    ///         digest: "a5864ede0c4971b7eb12c14b27069902e8bb32691d997a55ac71c4831cdd01e2".to_string(),
-    ///     };
+    ///     let partial_model = PartialModel::new("llama3.1:8b-instruct-q6_K");
     ///     let model = partial_model.to_model(Some(&client)).await.unwrap();
     /// }
     /// ```
     #[cfg(feature = "async")]
-    pub async fn to_model(&self, client: Option<&Ollama>) -> Result<Model, Box<dyn Error>> {
-        let response = show(client, &self.model, Some(true)).await?;
-        let mut model = Model::from_show_response(response);
-        model.name = self.name.clone();  // Ensure the name matches the PartialModel name
-        Ok(model)
+    pub async fn to_model(&self, client: Option<&Ollama>) -> Result<Model, OllamaError> {
+        let response = self.show(client).await?;
+        Ok(self.with_identity(Model::from_show_response(response)))
     }
 
-    /// Converts a `PartialModel` to a full `Model` by calling the `show` API.
+    /// Converts a `PartialModel` to a full `Model` by calling the `show` API, blocking the current thread.
     ///
     /// # Arguments
     ///
@@ -62,64 +115,177 @@ impl PartialModel {
     ///
     /// A `Result` containing a `Model` instance or an error if the request fails.
     ///
+    /// # Errors
+    ///
+    /// Returns an error if the `show` request fails.
+    ///
     /// # Examples
     ///
-    /// ```
+    /// ```no_run
     /// use ollama::prelude::*;
     ///
     /// let client = Ollama::new().with_host("http://0.0.0.0").with_port(11434);
-    /// let partial_model = PartialModel {
-    ///     name: "llama3.1:8b-instruct-q6_K".to_string(),
-    ///     model: "llama3.1:8b-instruct-q6_K".to_string(),
-    ///     modified_at: "2024-08-26T13:02:58.883873254+01:00".to_string(),
-    ///     size: 2490902249,
-    ///     digest: "a5864ede0c4971b7eb12c14b27069902e8bb32691d997a55ac71c4831cdd01e2".to_string(),
-    /// };
-    /// let model = partial_model.to_model(Some(&client)).unwrap();
+    /// let partial_model = PartialModel::new("llama3.1:8b-instruct-q6_K");
+    /// let model = partial_model.to_model_blocking(Some(&client)).unwrap();
     /// ```
-    #[cfg(not(feature = "async"))]
-    pub fn to_model(&self, client: Option<&Ollama>) -> Result<Model, Box<dyn Error>> {
-        let response = show(client, &self.model, Some(true))?;
-        let mut model = Model::from_show_response(response);
-        model.name = self.name.clone();  // Ensure the name matches the PartialModel name
-        Ok(model)
+    #[cfg(feature = "sync")]
+    pub fn to_model_blocking(&self, client: Option<&Ollama>) -> Result<Model, OllamaError> {
+        let response = self.show_blocking(client)?;
+        Ok(self.with_identity(Model::from_show_response(response)))
+    }
+
+    /// Copies the fields `show` does not return from this `list` entry into `model`.
+    fn with_identity(&self, model: Model) -> Model {
+        Model {
+            name: self.name.clone(),
+            model: self.model.clone(),
+            modified_at: self.modified_at.clone(),
+            size: self.size,
+            digest: self.digest.clone(),
+            ..model
+        }
+    }
+}
+
+impl fmt::Display for PartialModel {
+    /// Formats the model as e.g. `llama3.1:8b (2.3 GB, modified 2024-08-26)`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({}", self.name, self.size_human())?;
+        if let Some(date) = self.modified_at.get(..10) {
+            write!(f, ", modified {date}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Formats a byte count using binary units, e.g. `2.3 GB`.
+pub(crate) fn size_human(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KB", "MB", "GB", "TB"];
+
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    // Precision loss is irrelevant at one decimal place.
+    #[allow(clippy::cast_precision_loss)]
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
     }
+    format!("{size:.1} {}", UNITS[unit])
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::api::client::Ollama;
-    use crate::constants::TEST_ENDPOINT_HOST;
-    use crate::constants::TEST_ENDPOINT_PORT;
+    use crate::api::transport::MockTransport;
+    use crate::constants::TEST_SHOW_RESPONSE;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_digest_json_compatibility() {
+        let empty: PartialModel = serde_json::from_str(r#"{"name":"a","model":"a","modified_at":"","size":0,"digest":""}"#).unwrap();
+        let absent: PartialModel = serde_json::from_str(r#"{"name":"a","model":"a","modified_at":"","size":0}"#).unwrap();
+        assert_eq!(empty.digest, None);
+        assert_eq!(absent, empty);
+        assert!(serde_json::to_value(&empty).unwrap().get("digest").is_none());
+
+        let hex = "a5864ede0c4971b7eb12c14b27069902e8bb32691d997a55ac71c4831cdd01e2";
+        let json = format!(r#"{{"name":"a","model":"a","modified_at":"","size":0,"digest":"{hex}"}}"#);
+        let known: PartialModel = serde_json::from_str(&json).unwrap();
+        assert_eq!(known.digest.as_ref().map(Digest::as_hex), Some(hex));
+        assert_eq!(serde_json::to_value(&known).unwrap()["digest"], hex);
+        assert!(serde_json::from_str::<PartialModel>(&json.replace(hex, "abc")).is_err());
+    }
 
-    #[cfg(not(feature = "async"))]
+    #[test]
+    fn test_display() {
+        let mut partial_model = PartialModel::new("llama3.1:8b");
+        partial_model.size = 2_490_902_249;
+        partial_model.modified_at = "2024-08-26T13:02:58.883873254+01:00".to_string();
+        assert_eq!(partial_model.to_string(), "llama3.1:8b (2.3 GB, modified 2024-08-26)");
+
+        partial_model.modified_at.clear();
+        assert_eq!(partial_model.to_string(), "llama3.1:8b (2.3 GB)");
+        assert_eq!(size_human(512), "512 B");
+        assert_eq!(size_human(1536), "1.5 KB");
+    }
+
+    #[cfg(feature = "sync")]
     #[test]
     fn test_to_model_sync() {
-        let client = Ollama::new().with_host(TEST_ENDPOINT_HOST).with_port(TEST_ENDPOINT_PORT);
+        let mock = Arc::new(MockTransport::new().with_response("/api/show", TEST_SHOW_RESPONSE));
+        let client = Ollama::new().with_transport(mock);
         let partial_model = PartialModel {
             name: "llama3.1:8b-instruct-q6_K".to_string(),
             model: "llama3.1:8b-instruct-q6_K".to_string(),
             modified_at: "2024-08-26T13:02:58.883873254+01:00".to_string(),
             size: 2490902249,
-            digest: "a5864ede0c4971b7eb12c14b27069902e8bb32691d997a55ac71c4831cdd01e2".to_string(),
+            digest: "a5864ede0c4971b7eb12c14b27069902e8bb32691d997a55ac71c4831cdd01e2".parse().ok(),
+            details: None,
         };
-        let model = partial_model.to_model(Some(&client)).unwrap();
+        let model = partial_model.to_model_blocking(Some(&client)).unwrap();
         assert_eq!(model.name, partial_model.name);
+        assert_eq!(model.size, partial_model.size);
+        assert_eq!(model.digest, partial_model.digest);
+        assert_eq!(model.details.family.as_deref(), Some("llama"));
     }
 
     #[cfg(feature = "async")]
     #[tokio::test]
     async fn test_to_model_async() {
-        let client = Ollama::new().with_host(TEST_ENDPOINT_HOST).with_port(TEST_ENDPOINT_PORT);
+        let mock = Arc::new(MockTransport::new().with_response("/api/show", TEST_SHOW_RESPONSE));
+        let client = Ollama::new().with_transport(mock);
         let partial_model = PartialModel {
             name: "llama3.1:8b-instruct-q6_K".to_string(),
             model: "llama3.1:8b-instruct-q6_K".to_string(),
             modified_at: "2024-08-26T13:02:58.883873254+01:00".to_string(),
             size: 2490902249,
-            digest: "a5864ede0c4971b7eb12c14b27069902e8bb32691d997a55ac71c4831cdd01e2".to_string(),
+            digest: "a5864ede0c4971b7eb12c14b27069902e8bb32691d997a55ac71c4831cdd01e2".parse().ok(),
+            details: None,
         };
         let model = partial_model.to_model(Some(&client)).await.unwrap();
         assert_eq!(model.name, partial_model.name);
+        assert_eq!(model.size, partial_model.size);
+        assert_eq!(model.digest, partial_model.digest);
+        assert_eq!(model.details.family.as_deref(), Some("llama"));
+    }
+
+    #[cfg(feature = "sync")]
+    #[test]
+    fn test_show_sync() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/show", TEST_SHOW_RESPONSE));
+        let client = Ollama::new().with_transport(mock.clone());
+        let partial_model: PartialModel = serde_json::from_str(
+            r#"{"name":"llama3.1:latest","model":"llama3.1:8b-instruct-q6_K","modified_at":"","size":0,"digest":""}"#,
+        )
+        .unwrap();
+
+        let response = partial_model.show_blocking(Some(&client)).unwrap();
+        assert_eq!(response.architecture(), Some("llama"));
+        assert_eq!(
+            mock.requests()[0].body,
+            Some(serde_json::json!({ "name": "llama3.1:8b-instruct-q6_K", "verbose": true }))
+        );
+    }
+
+    #[cfg(feature = "async")]
+    #[tokio::test]
+    async fn test_show_async() {
+        let mock = Arc::new(MockTransport::new().with_response("/api/show", TEST_SHOW_RESPONSE));
+        let client = Ollama::new().with_transport(mock.clone());
+        let partial_model: PartialModel = serde_json::from_str(
+            r#"{"name":"llama3.1:latest","model":"llama3.1:8b-instruct-q6_K","modified_at":"","size":0,"digest":""}"#,
+        )
+        .unwrap();
+
+        let response = partial_model.show(Some(&client)).await.unwrap();
+        assert_eq!(response.architecture(), Some("llama"));
+        assert_eq!(
+            mock.requests()[0].body,
+            Some(serde_json::json!({ "name": "llama3.1:8b-instruct-q6_K", "verbose": true }))
+        );
     }
 }
@@ -0,0 +1,138 @@
+//! Optional [`tracing`](https://docs.rs/tracing) instrumentation of API calls.
+//!
+//! With the `tracing` feature, every call runs inside an `info` span (e.g. `ollama.show`)
+//! and emits events when it starts, finishes and fails. Without it, these are no-ops.
+//! With the `logging` feature as well, the crate's `log` records are forwarded to the
+//! `tracing` subscriber; see [`init_logger`](crate::logging::init_logger).
+
+use crate::error::OllamaError;
+
+/// Emits the event marking the start of a request.
+// Only const without the `tracing` feature.
+#[allow(clippy::missing_const_for_fn)]
+pub fn start() {
+    #[cfg(feature = "tracing")]
+    tracing::info!("request started");
+}
+
+/// Emits the event for the outcome of a request and passes `result` through.
+#[allow(clippy::missing_const_for_fn)]
+pub fn finish<T>(result: Result<T, OllamaError>) -> Result<T, OllamaError> {
+    #[cfg(feature = "tracing")]
+    match &result {
+        Ok(_) => tracing::info!("request finished"),
+        Err(error) => tracing::error!(%error, "request failed"),
+    }
+    result
+}
+
+/// Runs the closure `$body` inside the span `$span`, emitting start and finish events.
+#[cfg(feature = "sync")]
+macro_rules! traced {
+    ($span:expr, $body:expr) => {{
+        #[cfg(feature = "tracing")]
+        let _entered = $span.entered();
+        crate::trace::start();
+        crate::trace::finish(($body)())
+    }};
+}
+
+/// Awaits the future `$body` inside the span `$span`, emitting start and finish events.
+#[cfg(feature = "async")]
+macro_rules! traced_async {
+    ($span:expr, $body:expr) => {{
+        let future = async {
+            crate::trace::start();
+            crate::trace::finish($body.await)
+        };
+        #[cfg(feature = "tracing")]
+        let future = tracing::Instrument::instrument(future, $span);
+        future.await
+    }};
+}
+
+#[cfg(feature = "sync")]
+pub(crate) use traced;
+#[cfg(feature = "async")]
+pub(crate) use traced_async;
+
+#[cfg(all(test, feature = "tracing", feature = "sync"))]
+mod tests {
+    use crate::api::client::Ollama;
+    use crate::api::transport::MockTransport;
+    use crate::constants::{API_TAGS_ENDPOINT, TEST_TAGS_RESPONSE};
+    use std::sync::{Arc, Mutex};
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Records the names of created spans and the messages of events, prefixing
+    /// those of records forwarded from `log` with `log `.
+    #[derive(Clone, Default)]
+    struct Recorder(Arc<Mutex<Vec<String>>>, bool);
+
+    impl Visit for Recorder {
+        fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+            if field.name() == "message" {
+                let prefix = if self.1 { "log " } else { "" };
+                self.0.lock().unwrap().push(format!("{prefix}{value:?}"));
+            }
+        }
+    }
+
+    impl Subscriber for Recorder {
+        fn enabled(&self, _: &Metadata<'_>) -> bool {
+            true
+        }
+        fn new_span(&self, span: &Attributes<'_>) -> Id {
+            self.0.lock().unwrap().push(span.metadata().name().to_string());
+            Id::from_u64(1)
+        }
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, event: &Event<'_>) {
+            let from_log = event.metadata().fields().field("log.target").is_some();
+            event.record(&mut Self(self.0.clone(), from_log));
+        }
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_list_emits_span_and_events() {
+        let mock = Arc::new(MockTransport::new().with_response(API_TAGS_ENDPOINT, TEST_TAGS_RESPONSE));
+        let client = Ollama::new().with_transport(mock);
+        let recorder = Recorder::default();
+
+        tracing::subscriber::with_default(recorder.clone(), || {
+            crate::api::sync::list(Some(&client)).unwrap();
+            crate::api::sync::show(Some(&client), "llama3.1", None).unwrap_err();
+        });
+
+        // With `logging`, the crate's own `log` records are forwarded here too.
+        let mut messages = recorder.0.lock().unwrap().clone();
+        messages.retain(|message| !message.starts_with("log "));
+        assert_eq!(
+            messages,
+            [
+                "ollama.list",
+                "request started",
+                "request finished",
+                "ollama.show",
+                "request started",
+                "request failed"
+            ]
+        );
+    }
+
+    #[cfg(feature = "logging")]
+    #[test]
+    fn test_log_records_reach_tracing() {
+        crate::logging::init_logger();
+        let recorder = Recorder::default();
+
+        tracing::subscriber::with_default(recorder.clone(), || log::info!("from log"));
+
+        assert_eq!(*recorder.0.lock().unwrap(), ["log from log"]);
+    }
+}
@@ -0,0 +1,37 @@
+//! The default client is process-global and can be set only once, so it is tested in its own
+//! binary rather than alongside the unit tests, which rely on the built-in default.
+
+use ollama::prelude::*;
+use std::sync::Arc;
+
+const TAGS_RESPONSE: &str = include_str!("fixtures/tags.json");
+
+/// Installs a default client backed by a mock and checks that it cannot be replaced.
+fn install_default_client() -> Arc<MockTransport> {
+    let mock = Arc::new(MockTransport::new().with_response("/api/tags", TAGS_RESPONSE));
+    let client = Ollama::new().with_host("http://10.0.0.2").with_port(8080).with_transport(mock.clone());
+    assert!(set_default_client(client).is_ok());
+    assert_eq!(default_client().base_url(), "http://10.0.0.2:8080");
+
+    let rejected = set_default_client(Ollama::new()).unwrap_err();
+    assert_eq!(rejected.base_url(), "http://localhost:11434");
+    mock
+}
+
+#[cfg(feature = "sync")]
+#[test]
+fn test_set_default_client() {
+    let mock = install_default_client();
+
+    assert!(!ollama::api::sync::list(None).unwrap().is_empty());
+    assert_eq!(mock.requests()[0].url, "http://10.0.0.2:8080/api/tags");
+}
+
+#[cfg(all(feature = "async", not(feature = "sync")))]
+#[tokio::test]
+async fn test_set_default_client() {
+    let mock = install_default_client();
+
+    assert!(!ollama::api::r#async::list(None).await.unwrap().is_empty());
+    assert_eq!(mock.requests()[0].url, "http://10.0.0.2:8080/api/tags");
+}